@@ -0,0 +1,140 @@
+/// A base prefix, such as `0x`, and the radix that it denotes
+///
+/// A table of `BasePrefix` values can be passed to
+/// [`ParseOptions::prefixes()`](crate::ParseOptions::prefixes()) in order to
+/// recognize prefixes other than the default `0x`, `0o`, and `0b`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_with, BasePrefix, ParseOptions};
+///
+/// static BASIC: [BasePrefix; 3] = [
+///     BasePrefix::new("&H", 16).ignore_case(true),
+///     BasePrefix::new("&O", 8).ignore_case(true),
+///     BasePrefix::new("&B", 2).ignore_case(true),
+/// ];
+///
+/// let opts = ParseOptions::new().prefixes(&BASIC);
+/// assert_eq!(strtoint_with::<u32>("&HFF", &opts).unwrap(), 255);
+/// assert_eq!(strtoint_with::<u32>("&o17", &opts).unwrap(), 15);
+/// assert_eq!(strtoint_with::<i32>("-&b101", &opts).unwrap(), -5);
+/// assert!(strtoint_with::<u32>("0xFF", &opts).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BasePrefix {
+    text: &'static str,
+    radix: u32,
+    ignore_case: bool,
+}
+
+impl BasePrefix {
+    /// The hexadecimal prefix `0x`
+    pub const HEX: BasePrefix = BasePrefix::new("0x", 16);
+
+    /// The octal prefix `0o`
+    pub const OCTAL: BasePrefix = BasePrefix::new("0o", 8);
+
+    /// The binary prefix `0b`
+    pub const BINARY: BasePrefix = BasePrefix::new("0b", 2);
+
+    /// The prefixes recognized by default: [`BasePrefix::HEX`],
+    /// [`BasePrefix::OCTAL`], and [`BasePrefix::BINARY`]
+    pub const DEFAULTS: &'static [BasePrefix] =
+        &[BasePrefix::HEX, BasePrefix::OCTAL, BasePrefix::BINARY];
+
+    /// Create a new `BasePrefix` denoting that numbers starting with `text`
+    /// are in radix `radix`.  The prefix is matched case-sensitively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` is empty or if `radix` is not in the range `2..=36`.
+    pub const fn new(text: &'static str, radix: u32) -> BasePrefix {
+        assert!(!text.is_empty(), "base prefix must not be empty");
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the range 2..=36"
+        );
+        BasePrefix {
+            text,
+            radix,
+            ignore_case: false,
+        }
+    }
+
+    /// Set whether the prefix is matched ASCII-case-insensitively, so that,
+    /// e.g., `0x` also matches `0X`.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn ignore_case(mut self, yes: bool) -> BasePrefix {
+        self.ignore_case = yes;
+        self
+    }
+
+    /// Return the text of the prefix
+    pub const fn text(&self) -> &'static str {
+        self.text
+    }
+
+    /// Return the radix denoted by the prefix
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Return whether the prefix is matched case-insensitively
+    pub const fn is_case_insensitive(&self) -> bool {
+        self.ignore_case
+    }
+
+    /// Return the length of the prefix in bytes
+    pub(crate) const fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Return the offset in bytes of the last character of the prefix
+    pub(crate) fn last_char_offset(&self) -> usize {
+        self.text.char_indices().last().map_or(0, |(i, _)| i)
+    }
+
+    /// If `s` starts with the prefix, return the length of the match in
+    /// bytes.  If `zero_len` is nonzero, `s` starts with a non-ASCII decimal
+    /// zero of that many bytes, which is matched against a `0` at the start
+    /// of the prefix.
+    fn match_len(&self, s: &[u8], zero_len: usize) -> Option<usize> {
+        let text = self.text.as_bytes();
+        let text = if zero_len > 0 {
+            text.strip_prefix(b"0")?
+        } else {
+            text
+        };
+        let head = s.get(zero_len..zero_len + text.len())?;
+        let matched = if self.ignore_case {
+            head.eq_ignore_ascii_case(text)
+        } else {
+            head == text
+        };
+        matched.then_some(zero_len + text.len())
+    }
+
+    /// Return the longest prefix in `table` that `s` starts with, along with
+    /// the length of the match in `s`.  If multiple prefixes of the same
+    /// length match, the first one is returned.  `zero_len` is as for
+    /// [`BasePrefix::match_len()`].
+    pub(crate) fn longest_match<I>(
+        table: I,
+        s: &[u8],
+        zero_len: usize,
+    ) -> Option<(BasePrefix, usize)>
+    where
+        I: IntoIterator<Item = BasePrefix>,
+    {
+        table
+            .into_iter()
+            .filter_map(|p| Some((p, p.match_len(s, zero_len)?)))
+            .fold(None, |best, (p, len)| match best {
+                Some((b, blen)) if blen >= len => Some((b, blen)),
+                _ => Some((p, len)),
+            })
+    }
+}
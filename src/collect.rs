@@ -0,0 +1,188 @@
+use crate::{ParseOptions, StrToInt, StrToIntError};
+use core::fmt;
+
+/// Parse an integer from a string, collecting every error in the input
+/// instead of stopping at the first one.
+///
+/// This function returns the same result as [`strtoint()`](crate::strtoint()),
+/// but, in addition, every problem found in the input is pushed to `errors`,
+/// so that they can all be reported to the user at once.  After an error is
+/// found, scanning continues as follows:
+///
+/// - An invalid character is skipped.
+/// - A `-` sign for an unsigned type is reported and then ignored.
+/// - Once the value goes out of range, only the first out-of-range digit is
+///   reported, but the rest of the input is still checked for invalid
+///   characters.
+/// - A [`StrToIntError::NoDigits`] error is reported after any invalid
+///   characters if the input contains no digits.
+/// - A [`StrToIntError::Zero`] error for a `NonZero` type is only reported if
+///   the input contains no other errors.
+///
+/// Errors are pushed to `errors` in the order in which they are found, which
+/// is generally (but not always) the order of their positions in the input.
+/// The first error pushed is always the same as the error returned.  If the
+/// input is valid, nothing is pushed.
+///
+/// `errors` can be any type implementing [`ErrorSink`], such as a
+/// `Vec<StrToIntError>` (with the `alloc` feature) or an [`ErrorArray`].
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint()`](crate::strtoint()), and the error returned will be the same
+/// as that returned by `strtoint()`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_all_errors, ErrorArray, InvalidReason, StrToIntError};
+///
+/// let mut errors = ErrorArray::<8>::new();
+/// let r = strtoint_all_errors::<u32>("0x12G4Z", &mut errors);
+/// assert_eq!(
+///     errors.as_slice(),
+///     [
+///         StrToIntError::InvalidCharacter {
+///             c: 'G',
+///             position: 4,
+///             reason: InvalidReason::InvalidDigit,
+///         },
+///         StrToIntError::InvalidCharacter {
+///             c: 'Z',
+///             position: 6,
+///             reason: InvalidReason::InvalidDigit,
+///         },
+///     ]
+/// );
+/// assert_eq!(r, Err(errors.as_slice()[0]));
+/// ```
+pub fn strtoint_all_errors<T: StrToInt>(
+    s: &str,
+    errors: &mut dyn ErrorSink,
+) -> Result<T, <T as StrToInt>::Err> {
+    T::strtoint_all_errors_with(s, &ParseOptions::new(), errors)
+}
+
+/// Parse an integer from a string using the given options, collecting every
+/// error in the input instead of stopping at the first one.
+///
+/// This is the [`ParseOptions`]-accepting equivalent of
+/// [`strtoint_all_errors()`].
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_with()`](crate::strtoint_with()), and the error returned will
+/// be the same as that returned by `strtoint_with()`.
+pub fn strtoint_all_errors_with<T: StrToInt>(
+    s: &str,
+    options: &ParseOptions,
+    errors: &mut dyn ErrorSink,
+) -> Result<T, <T as StrToInt>::Err> {
+    T::strtoint_all_errors_with(s, options, errors)
+}
+
+/// A collection to which [`strtoint_all_errors()`] and
+/// [`strtoint_all_errors_with()`] can push errors
+///
+/// This trait is implemented for [`ErrorArray`] and, with the `alloc`
+/// feature, for `Vec<StrToIntError>`.
+pub trait ErrorSink {
+    /// Add an error to the collection
+    fn push(&mut self, error: StrToIntError);
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl ErrorSink for alloc::vec::Vec<StrToIntError> {
+    fn push(&mut self, error: StrToIntError) {
+        alloc::vec::Vec::push(self, error);
+    }
+}
+
+/// A fixed-capacity collection of up to `N` errors that does not require
+/// allocation
+///
+/// Once an `ErrorArray` is full, any further errors pushed to it are
+/// discarded, and only the number of discarded errors is kept.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_all_errors, ErrorArray};
+///
+/// let mut errors = ErrorArray::<2>::new();
+/// assert!(strtoint_all_errors::<u32>("a b c", &mut errors).is_err());
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.discarded(), 4);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct ErrorArray<const N: usize> {
+    errors: [StrToIntError; N],
+    len: usize,
+    discarded: usize,
+}
+
+impl<const N: usize> ErrorArray<N> {
+    /// Create a new, empty `ErrorArray`
+    pub const fn new() -> ErrorArray<N> {
+        ErrorArray {
+            errors: [StrToIntError::NoDigits; N],
+            len: 0,
+            discarded: 0,
+        }
+    }
+
+    /// Return the errors in the collection as a slice
+    pub fn as_slice(&self) -> &[StrToIntError] {
+        &self.errors[..self.len]
+    }
+
+    /// Return the number of errors in the collection
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the collection contains no errors
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the number of errors that were discarded because the collection
+    /// was full
+    pub const fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Remove all errors from the collection and reset the discarded count
+    pub fn clear(&mut self) {
+        *self = ErrorArray::new();
+    }
+}
+
+impl<const N: usize> Default for ErrorArray<N> {
+    fn default() -> ErrorArray<N> {
+        ErrorArray::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for ErrorArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorArray")
+            .field("errors", &self.as_slice())
+            .field("discarded", &self.discarded)
+            .finish()
+    }
+}
+
+impl<const N: usize> ErrorSink for ErrorArray<N> {
+    fn push(&mut self, error: StrToIntError) {
+        if let Some(slot) = self.errors.get_mut(self.len) {
+            *slot = error;
+            self.len += 1;
+        } else {
+            self.discarded += 1;
+        }
+    }
+}
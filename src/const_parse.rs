@@ -0,0 +1,374 @@
+// As trait methods cannot be called in `const` contexts, this module
+// reimplements the default grammar of `strtoint()` as a standalone `const fn`.
+use crate::{InvalidReason, StrToIntError};
+
+/// Parse `s` into a sign and a magnitude according to the same rules as
+/// [`strtoint()`](crate::strtoint()), for a type whose signedness and range
+/// are described by `signed`, `max_magnitude`, and `min_magnitude`
+const fn parse_magnitude(
+    s: &[u8],
+    signed: bool,
+    max_magnitude: u128,
+    min_magnitude: u128,
+) -> Result<(bool, u128), StrToIntError> {
+    let mut i = 0;
+    let mut negative = false;
+    if let [b'+', ..] = s {
+        i = 1;
+    } else if let [b'-', ..] = s {
+        if !signed {
+            return Err(StrToIntError::InvalidCharacter {
+                c: '-',
+                position: 0,
+                reason: InvalidReason::UnsignedMinus,
+            });
+        }
+        negative = true;
+        i = 1;
+    }
+    let mut radix = 10;
+    if i + 1 < s.len() && s[i] == b'0' {
+        radix = match s[i + 1] {
+            b'x' => 16,
+            b'o' => 8,
+            b'b' => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            i += 2;
+        }
+    }
+    let limit = if negative {
+        min_magnitude
+    } else {
+        max_magnitude
+    };
+    let mut magnitude = 0u128;
+    let mut digit_seen = false;
+    while i < s.len() {
+        let b = s[i];
+        if b == b'_' {
+            if !digit_seen && radix == 10 {
+                return Err(StrToIntError::InvalidCharacter {
+                    c: '_',
+                    position: i,
+                    reason: InvalidReason::LeadingUnderscore,
+                });
+            }
+            i += 1;
+            continue;
+        }
+        let Some(digit) = (b as char).to_digit(radix) else {
+            return Err(StrToIntError::InvalidCharacter {
+                c: decode_char(s, i),
+                position: i,
+                reason: InvalidReason::classify(b, i, 0, radix != 10, digit_seen),
+            });
+        };
+        let next = match magnitude.checked_mul(radix as u128) {
+            Some(m) => m.checked_add(digit as u128),
+            None => None,
+        };
+        magnitude = match next {
+            Some(m) if m <= limit => m,
+            _ => {
+                return Err(StrToIntError::out_of_range(
+                    negative,
+                    min_magnitude,
+                    max_magnitude,
+                    i,
+                ))
+            }
+        };
+        digit_seen = true;
+        i += 1;
+    }
+    if !digit_seen {
+        return Err(StrToIntError::NoDigits);
+    }
+    Ok((negative, magnitude))
+}
+
+/// Decode the character starting at index `i` of the UTF-8 string `s`
+const fn decode_char(s: &[u8], i: usize) -> char {
+    let lead = s[i] as u32;
+    let (len, mut c) = if lead < 0x80 {
+        (1, lead)
+    } else if lead >= 0xF0 {
+        (4, lead & 0x07)
+    } else if lead >= 0xE0 {
+        (3, lead & 0x0F)
+    } else {
+        (2, lead & 0x1F)
+    };
+    let mut j = 1;
+    while j < len && i + j < s.len() {
+        c = (c << 6) | (s[i + j] as u32 & 0x3F);
+        j += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+macro_rules! impl_const_parse {
+    ($($t:ident => $f:ident),* $(,)?) => {
+      $(
+        #[doc = concat!("Parse a string as a `", stringify!($t), "` in a `const` context.")]
+        ///
+        /// This function accepts exactly the same syntax as
+        /// [`strtoint()`](crate::strtoint()) and returns the same errors, but
+        /// it can be used to initialize `const` and `static` items.  See also
+        /// the [`strtoint!`](crate::strtoint!) macro, which turns parse
+        /// errors into compile errors.
+        ///
+        /// # Errors
+        ///
+        /// This function will return an error under the same conditions as
+        /// [`strtoint()`](crate::strtoint()).
+        pub const fn $f(s: &str) -> Result<$t, StrToIntError> {
+            match parse_magnitude(
+                s.as_bytes(),
+                <$t>::MIN != 0,
+                <$t>::MAX as u128,
+                (<$t>::MIN as i128).unsigned_abs(),
+            ) {
+                Ok((true, magnitude)) => Ok((magnitude as $t).wrapping_neg()),
+                Ok((false, magnitude)) => Ok(magnitude as $t),
+                Err(e) => Err(e),
+            }
+        }
+      )*
+    };
+}
+
+impl_const_parse!(
+    i8 => const_parse_i8,
+    i16 => const_parse_i16,
+    i32 => const_parse_i32,
+    i64 => const_parse_i64,
+    i128 => const_parse_i128,
+    isize => const_parse_isize,
+    u8 => const_parse_u8,
+    u16 => const_parse_u16,
+    u32 => const_parse_u32,
+    u64 => const_parse_u64,
+    u128 => const_parse_u128,
+    usize => const_parse_usize,
+);
+
+/// Parse an integer string literal at compile time.
+///
+/// `strtoint!(T, s)` evaluates to the value of the string `s` parsed as the
+/// primitive integer type `T` according to the same rules as
+/// [`strtoint()`](crate::strtoint()).  `s` must be a constant expression of
+/// type `&str`, such as a string literal or an invocation of [`env!`].  If
+/// `s` is not a valid integer for `T`, compilation fails with an error
+/// message that shows the input and points at the offending character.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint;
+///
+/// const MASK: u32 = strtoint!(u32, "0xFFFF_0000");
+/// assert_eq!(MASK, 0xFFFF_0000);
+/// assert_eq!(strtoint!(i8, "-0b101"), -5);
+/// ```
+///
+/// ```compile_fail
+/// let x = strtoint::strtoint!(u8, "0x1G");
+/// ```
+#[macro_export]
+macro_rules! strtoint {
+    (i8, $s:expr) => {
+        $crate::__strtoint_const!(i8, const_parse_i8, $s)
+    };
+    (i16, $s:expr) => {
+        $crate::__strtoint_const!(i16, const_parse_i16, $s)
+    };
+    (i32, $s:expr) => {
+        $crate::__strtoint_const!(i32, const_parse_i32, $s)
+    };
+    (i64, $s:expr) => {
+        $crate::__strtoint_const!(i64, const_parse_i64, $s)
+    };
+    (i128, $s:expr) => {
+        $crate::__strtoint_const!(i128, const_parse_i128, $s)
+    };
+    (isize, $s:expr) => {
+        $crate::__strtoint_const!(isize, const_parse_isize, $s)
+    };
+    (u8, $s:expr) => {
+        $crate::__strtoint_const!(u8, const_parse_u8, $s)
+    };
+    (u16, $s:expr) => {
+        $crate::__strtoint_const!(u16, const_parse_u16, $s)
+    };
+    (u32, $s:expr) => {
+        $crate::__strtoint_const!(u32, const_parse_u32, $s)
+    };
+    (u64, $s:expr) => {
+        $crate::__strtoint_const!(u64, const_parse_u64, $s)
+    };
+    (u128, $s:expr) => {
+        $crate::__strtoint_const!(u128, const_parse_u128, $s)
+    };
+    (usize, $s:expr) => {
+        $crate::__strtoint_const!(usize, const_parse_usize, $s)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strtoint_const {
+    ($t:ident, $f:ident, $s:expr) => {{
+        const VALUE: $t = match $crate::$f($s) {
+            Ok(value) => value,
+            Err(e) => $crate::__private::compile_error($s, stringify!($t), e),
+        };
+        VALUE
+    }};
+}
+
+/// Items used by the expansion of [`strtoint!`](crate::strtoint!); not part
+/// of the public API
+#[doc(hidden)]
+pub mod __private {
+    use crate::{OutOfRangeKind, StrToIntError};
+
+    /// Panic (at compile time, when used in a `const` item) with a message
+    /// describing why `s` could not be parsed as the type named `ty`
+    pub const fn compile_error(s: &str, ty: &str, e: StrToIntError) -> ! {
+        let msg = Message::new();
+        let msg = match e {
+            StrToIntError::InvalidCharacter { position, .. } => {
+                let (_, rest) = s.as_bytes().split_at(position);
+                let c = match core::str::from_utf8(rest.split_at(char_len(rest[0])).0) {
+                    Ok(c) => c,
+                    Err(_) => "",
+                };
+                msg.push("invalid character '")
+                    .push(c)
+                    .push("' at position ")
+                    .push_usize(position)
+                    .push(" in integer literal:\n    ")
+                    .push(s)
+                    .push("\n    ")
+                    .push_spaces(char_count(s.as_bytes().split_at(position).0))
+                    .push("^")
+            }
+            StrToIntError::NoDigits => msg.push("no digits in integer literal:\n    ").push(s),
+            StrToIntError::OutOfRange {
+                kind: OutOfRangeKind::TooSmall,
+                ..
+            } => msg
+                .push("integer literal is too small for `")
+                .push(ty)
+                .push("`:\n    ")
+                .push(s),
+            _ => msg
+                .push("integer literal is too large for `")
+                .push(ty)
+                .push("`:\n    ")
+                .push(s),
+        };
+        panic!("{}", msg.as_str())
+    }
+
+    /// Return the length of the UTF-8 sequence starting with byte `lead`
+    const fn char_len(lead: u8) -> usize {
+        if lead < 0x80 {
+            1
+        } else if lead >= 0xF0 {
+            4
+        } else if lead >= 0xE0 {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// Return the number of characters in the UTF-8 string `s`
+    const fn char_count(s: &[u8]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] & 0xC0 != 0x80 {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    const MESSAGE_CAPACITY: usize = 512;
+
+    /// A fixed-capacity string buffer that can be built in a `const`
+    /// context.  Text that does not fit is silently dropped.
+    struct Message {
+        buf: [u8; MESSAGE_CAPACITY],
+        len: usize,
+    }
+
+    impl Message {
+        const fn new() -> Message {
+            Message {
+                buf: [0; MESSAGE_CAPACITY],
+                len: 0,
+            }
+        }
+
+        const fn push(mut self, s: &str) -> Message {
+            let s = s.as_bytes();
+            let mut end = s.len();
+            if end > MESSAGE_CAPACITY - self.len {
+                end = MESSAGE_CAPACITY - self.len;
+                // Don't split a multibyte character
+                while end > 0 && s[end] & 0xC0 == 0x80 {
+                    end -= 1;
+                }
+            }
+            let mut i = 0;
+            while i < end {
+                self.buf[self.len] = s[i];
+                self.len += 1;
+                i += 1;
+            }
+            self
+        }
+
+        const fn push_usize(self, mut n: usize) -> Message {
+            let mut digits = [0u8; 20];
+            let mut i = digits.len();
+            loop {
+                i -= 1;
+                digits[i] = b'0' + (n % 10) as u8;
+                n /= 10;
+                if n == 0 {
+                    break;
+                }
+            }
+            match core::str::from_utf8(digits.split_at(i).1) {
+                Ok(s) => self.push(s),
+                Err(_) => self,
+            }
+        }
+
+        const fn push_spaces(mut self, mut n: usize) -> Message {
+            while n > 0 {
+                self = self.push(" ");
+                n -= 1;
+            }
+            self
+        }
+
+        const fn as_str(&self) -> &str {
+            match core::str::from_utf8(self.buf.split_at(self.len).0) {
+                Ok(s) => s,
+                Err(_) => "",
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{English, ErrorMessages, StrToIntError};
+use core::fmt;
+
+/// A human-readable rendering of a [`StrToIntError`] that shows where in the
+/// input the error occurred
+///
+/// The `Display` implementation of a `Diagnostic` writes the error message,
+/// followed by the input on its own line, followed by a line with `^` markers
+/// under the offending character or span of the input and a description of
+/// the problem.  The input and marker lines are indented by four spaces, and
+/// any control characters in the input (such as tabs or newlines) are
+/// displayed as spaces so that the markers stay aligned.  Markers are aligned
+/// by counting characters, so input containing wide or combining characters
+/// may be displayed imprecisely.
+///
+/// `input` must be the same string that produced the error; if it is not, the
+/// markers will be placed at meaningless locations, though no panic will
+/// occur.  Note that the errors yielded by [`find_iter()`](crate::find_iter())
+/// have positions relative to the start of each match, and so the input for
+/// them must be the matched substring rather than the whole haystack.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint, Diagnostic};
+///
+/// let s = "1_000.0";
+/// let e = strtoint::<u32>(s).unwrap_err();
+/// assert_eq!(
+///     Diagnostic::new(s, e).to_string(),
+///     concat!(
+///         "invalid character '.' at position 5\n",
+///         "    1_000.0\n",
+///         "         ^ unexpected character after digits",
+///     )
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Diagnostic<'a> {
+    input: &'a str,
+    error: StrToIntError,
+    color: bool,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Create a `Diagnostic` for an error that occurred while parsing `input`
+    pub const fn new(input: &'a str, error: StrToIntError) -> Diagnostic<'a> {
+        Diagnostic {
+            input,
+            error,
+            color: false,
+        }
+    }
+
+    /// Set whether the diagnostic is colored & emboldened using ANSI escape
+    /// sequences.
+    ///
+    /// Default: `false`
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[must_use]
+    pub const fn color(mut self, yes: bool) -> Diagnostic<'a> {
+        self.color = yes;
+        self
+    }
+
+    /// Return the error being rendered
+    pub const fn error(&self) -> StrToIntError {
+        self.error
+    }
+
+    /// Return a value that renders the diagnostic using the error message
+    /// and marker label provided by `messages`; see [`ErrorMessages`] for
+    /// how to provide them
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::fmt;
+    /// use strtoint::{strtoint, Diagnostic, ErrorMessages, StrToIntError};
+    ///
+    /// struct Spanish;
+    ///
+    /// impl ErrorMessages for Spanish {
+    ///     fn no_digits(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "no hay dígitos en la entrada")
+    ///     }
+    ///
+    ///     fn diagnostic_label(
+    ///         &self,
+    ///         f: &mut fmt::Formatter<'_>,
+    ///         _error: StrToIntError,
+    ///     ) -> fmt::Result {
+    ///         write!(f, "aquí")
+    ///     }
+    /// }
+    ///
+    /// let s = "0x";
+    /// let e = strtoint::<u32>(s).unwrap_err();
+    /// assert_eq!(
+    ///     Diagnostic::new(s, e).localized(&Spanish).to_string(),
+    ///     concat!(
+    ///         "no hay dígitos en la entrada\n",
+    ///         "    0x\n",
+    ///         "      ^ aquí",
+    ///     )
+    /// );
+    /// ```
+    pub fn localized<'b, M: ErrorMessages + ?Sized>(
+        &self,
+        messages: &'b M,
+    ) -> LocalizedDiagnostic<'a, 'b, M> {
+        LocalizedDiagnostic {
+            diagnostic: *self,
+            messages,
+        }
+    }
+
+    /// Return the byte offset and character width of the span of input to
+    /// mark
+    fn span(&self) -> (usize, usize) {
+        match self.error {
+            StrToIntError::NoDigits => (self.input.len(), 1),
+            StrToIntError::InvalidCharacter { position, .. }
+            | StrToIntError::OutOfRange { position, .. }
+            | StrToIntError::ExceedsWidth { position, .. }
+            | StrToIntError::UnknownDigit { position, .. } => (position, 1),
+            StrToIntError::SuffixMismatch { suffix, position } => (position, suffix.as_str().len()),
+            StrToIntError::InvalidRadix { position, .. } => (
+                position,
+                self.input
+                    .get(position..)
+                    .map_or(0, |s| s.bytes().take_while(u8::is_ascii_digit).count())
+                    .max(1),
+            ),
+            StrToIntError::Zero => (0, self.input.chars().count().max(1)),
+        }
+    }
+
+    /// Write the diagnostic using the messages from `messages`
+    fn render<M: ErrorMessages + ?Sized>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        messages: &M,
+    ) -> fmt::Result {
+        let (bold, red, reset) = if self.color {
+            ("\x1B[1m", "\x1B[1;31m", "\x1B[0m")
+        } else {
+            ("", "", "")
+        };
+        writeln!(f, "{bold}{}{reset}", self.error.localized(messages))?;
+        f.write_str("    ")?;
+        for c in self.input.chars() {
+            if c.is_control() {
+                f.write_str(" ")?;
+            } else {
+                write!(f, "{c}")?;
+            }
+        }
+        let (position, width) = self.span();
+        let column = self
+            .input
+            .get(..position)
+            .unwrap_or(self.input)
+            .chars()
+            .count();
+        write!(f, "\n    {:column$}{red}", "")?;
+        for _ in 0..width {
+            f.write_str("^")?;
+        }
+        f.write_str(" ")?;
+        messages.diagnostic_label(f, self.error)?;
+        f.write_str(reset)
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, &English)
+    }
+}
+
+/// A [`Diagnostic`] displayed using the messages from an [`ErrorMessages`]
+/// provider
+///
+/// This type is returned by [`Diagnostic::localized()`].
+pub struct LocalizedDiagnostic<'a, 'b, M: ?Sized> {
+    diagnostic: Diagnostic<'a>,
+    messages: &'b M,
+}
+
+impl<M: ?Sized> fmt::Debug for LocalizedDiagnostic<'_, '_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalizedDiagnostic")
+            .field("diagnostic", &self.diagnostic)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M: ErrorMessages + ?Sized> fmt::Display for LocalizedDiagnostic<'_, '_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.render(f, self.messages)
+    }
+}
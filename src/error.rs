@@ -0,0 +1,235 @@
+use crate::{English, ErrorMessages, IntSuffix};
+use core::fmt;
+
+/// Error type for the [`strtoint()`](crate::strtoint()) function
+///
+/// This type is used as the error type for [`strtoint()`](crate::strtoint())
+/// and [`StrToInt`](crate::StrToInt) for all types covered by this crate.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum StrToIntError {
+    /// Returned when the input string contained no digits
+    NoDigits,
+    /// Returned when the input string contained an invalid character; `c` is
+    /// the character in question, `position` is its index in the input, and
+    /// `reason` describes which rule of the integer syntax was broken
+    InvalidCharacter {
+        c: char,
+        position: usize,
+        reason: InvalidReason,
+    },
+    /// Returned when the numeric value of the input string was out of range
+    /// for the numeric type; `kind` says whether the value was too large or
+    /// too small, `min` and `max` are the bounds of the numeric type, and
+    /// `position` is the index in the input of the digit at which the value
+    /// first went out of range.  For the `NonZero` types, `min` and `max`
+    /// are the bounds of the corresponding primitive type.
+    OutOfRange {
+        kind: OutOfRangeKind,
+        min: i128,
+        max: u128,
+        position: usize,
+    },
+    /// Returned when the input string ended in an integer type suffix for a
+    /// type other than the one being parsed; `suffix` is the suffix in
+    /// question, and `position` is the index of its start in the input
+    SuffixMismatch { suffix: IntSuffix, position: usize },
+    /// Returned when the input string gave an explicit radix (see
+    /// [`ParseOptions::hash_radix()`]) outside the range 2 through 36;
+    /// `radix` is the radix given (saturated to `u32::MAX`), and `position`
+    /// is the index of its first digit in the input
+    ///
+    /// [`ParseOptions::hash_radix()`]: crate::ParseOptions::hash_radix()
+    InvalidRadix { radix: u32, position: usize },
+    /// Returned when the value of a Verilog literal (see
+    /// [`ParseOptions::verilog_literals()`]) did not fit in its declared
+    /// width; `width` is the declared width in bits, and `position` is the
+    /// index in the input of the digit at which the value first exceeded it
+    ///
+    /// [`ParseOptions::verilog_literals()`]: crate::ParseOptions::verilog_literals()
+    ExceedsWidth { width: u32, position: usize },
+    /// Returned when a Verilog literal (see
+    /// [`ParseOptions::verilog_literals()`]) contained an `x`, `z`, or `?`
+    /// digit, which denotes an unknown or high-impedance bit that cannot be
+    /// represented in an integer; `c` is the digit in question, and
+    /// `position` is its index in the input
+    ///
+    /// [`ParseOptions::verilog_literals()`]: crate::ParseOptions::verilog_literals()
+    UnknownDigit { c: char, position: usize },
+    /// Returned when the numeric value of the input string was zero and the
+    /// numeric type is one of the `NonZero` types
+    Zero,
+}
+
+impl fmt::Display for StrToIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.localized(&English).fmt(f)
+    }
+}
+
+impl StrToIntError {
+    /// Construct an `OutOfRange` error for a value with the given sign that
+    /// went out of range at `position`, for a type whose minimum and maximum
+    /// values have magnitudes `min_magnitude` and `max_magnitude`
+    pub(crate) const fn out_of_range(
+        negative: bool,
+        min_magnitude: u128,
+        max_magnitude: u128,
+        position: usize,
+    ) -> StrToIntError {
+        StrToIntError::OutOfRange {
+            kind: if negative {
+                OutOfRangeKind::TooSmall
+            } else {
+                OutOfRangeKind::TooLarge
+            },
+            min: 0i128.wrapping_sub_unsigned(min_magnitude),
+            max: max_magnitude,
+            position,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for StrToIntError {}
+
+/// The direction in which a value in a [`StrToIntError::OutOfRange`] error
+/// was out of range
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OutOfRangeKind {
+    /// The value was greater than the numeric type's maximum
+    TooLarge,
+    /// The value was less than the numeric type's minimum
+    TooSmall,
+}
+
+/// The rule of the integer syntax broken by the character reported in a
+/// [`StrToIntError::InvalidCharacter`] error
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidReason {
+    /// A `-` sign was given for an unsigned type, e.g., `"-1"` for `u8`
+    UnsignedMinus,
+    /// A leading sign was given that the parse options do not allow, e.g., a
+    /// `+` sign when [`ParseOptions::plus_sign()`] is `false` or the `-` in
+    /// `"-0x10"` when [`ParseOptions::signed_prefixes()`] is `false`
+    ///
+    /// [`ParseOptions::plus_sign()`]: crate::ParseOptions::plus_sign()
+    /// [`ParseOptions::signed_prefixes()`]: crate::ParseOptions::signed_prefixes()
+    DisallowedSign,
+    /// A sign appeared after a base prefix, e.g., `"0x-1"`
+    SignAfterPrefix,
+    /// A sign appeared somewhere other than the start of the input, e.g.,
+    /// `"+-1"` or `"1-2"`
+    MisplacedSign,
+    /// An underscore appeared before the first digit where underscores are
+    /// not allowed there, e.g., `"_1"`
+    LeadingUnderscore,
+    /// An underscore appeared after the first digit in a position where
+    /// underscores are not allowed, e.g., `"1__0"` under
+    /// [`Underscores::BetweenDigits`] or `"1_0"` under
+    /// [`Underscores::Forbidden`]
+    ///
+    /// [`Underscores::BetweenDigits`]: crate::Underscores::BetweenDigits
+    /// [`Underscores::Forbidden`]: crate::Underscores::Forbidden
+    MisplacedUnderscore,
+    /// An ASCII letter or digit that is not a valid digit in the radix of the
+    /// number, e.g., the `2` in `"0b102"` or the `g` in `"0xfg"`
+    InvalidDigit,
+    /// A character other than an ASCII letter, digit, sign, or underscore
+    /// appeared before any digits, e.g., `" 42"` or `".5"`
+    UnexpectedCharacter,
+    /// A character other than an ASCII letter, digit, sign, or underscore
+    /// appeared after a digit, e.g., `"42.0"` or `"1,000"`
+    TrailingCharacter,
+    /// The digits were followed by letters that do not form a recognized
+    /// integer type suffix, e.g., `"1u7"` when
+    /// [`ParseOptions::type_suffixes()`] is `true`
+    ///
+    /// [`ParseOptions::type_suffixes()`]: crate::ParseOptions::type_suffixes()
+    InvalidSuffix,
+    /// The `BigInt` suffix `n` under [`ParseOptions::bigint_suffix()`]
+    /// followed a number with a leading zero read under
+    /// [`ParseOptions::leading_zero_octal()`], e.g., the `n` in `"0777n"`
+    ///
+    /// [`ParseOptions::bigint_suffix()`]: crate::ParseOptions::bigint_suffix()
+    /// [`ParseOptions::leading_zero_octal()`]: crate::ParseOptions::leading_zero_octal()
+    DisallowedBigIntSuffix,
+    /// A base prefix was required by [`PrefixRule::Required`] but was not
+    /// present; the character reported is the first one after the sign
+    ///
+    /// [`PrefixRule::Required`]: crate::PrefixRule::Required
+    MissingPrefix,
+    /// A base prefix for a radix other than the default radix was present
+    /// under [`PrefixConflict::Error`], e.g., the `b` in `"0b101"` when the
+    /// default radix is 16
+    ///
+    /// [`PrefixConflict::Error`]: crate::PrefixConflict::Error
+    ConflictingPrefix,
+    /// A number without a base prefix started with a letter under
+    /// [`ParseOptions::radix_suffixes()`], e.g., the `F` in `"FFh"`
+    ///
+    /// [`ParseOptions::radix_suffixes()`]: crate::ParseOptions::radix_suffixes()
+    NoLeadingDigit,
+    /// A number in Ada notation under [`HashRadix::Ada`] did not end with a
+    /// closing `#`; the character reported is the opening `#`, e.g., the
+    /// `#` in `"16#FF"`
+    ///
+    /// [`HashRadix::Ada`]: crate::HashRadix::Ada
+    UnclosedHash,
+    /// A Verilog literal under [`ParseOptions::verilog_literals()`] declared
+    /// a width of zero; the character reported is the first digit of the
+    /// width, e.g., the `0` in `"0'h1"`
+    ///
+    /// [`ParseOptions::verilog_literals()`]: crate::ParseOptions::verilog_literals()
+    ZeroWidth,
+    /// A Verilog literal under [`ParseOptions::verilog_literals()`] declared
+    /// a width too large to fit in a `u32`; the character reported is the
+    /// first digit of the width, e.g., the first `9` in `"99999999999'h1"`
+    ///
+    /// [`ParseOptions::verilog_literals()`]: crate::ParseOptions::verilog_literals()
+    WidthTooLarge,
+    /// A number without a base prefix started with a zero followed by other
+    /// digits where [`ParseOptions::leading_zeros()`] does not allow it; the
+    /// character reported is the leading zero, e.g., the `0` in `"0644"`
+    ///
+    /// [`ParseOptions::leading_zeros()`]: crate::ParseOptions::leading_zeros()
+    LeadingZero,
+    /// A base-60 component of a number under [`ParseOptions::sexagesimal()`]
+    /// was not a number from 0 to 59 written with one or two digits; the
+    /// character reported is the first one after the colon, e.g., the `6` in
+    /// `"1:60"`, or the colon itself if it ends the input
+    ///
+    /// [`ParseOptions::sexagesimal()`]: crate::ParseOptions::sexagesimal()
+    InvalidSexagesimal,
+}
+
+impl InvalidReason {
+    /// Determine why the non-digit, non-underscore byte `b` at `position` was
+    /// rejected, given the position `start` at which the number starts (after
+    /// any leading whitespace), whether a base prefix was consumed, and
+    /// whether any digits have been seen
+    pub(crate) const fn classify(
+        b: u8,
+        position: usize,
+        start: usize,
+        prefixed: bool,
+        digit_seen: bool,
+    ) -> InvalidReason {
+        match b {
+            b'+' | b'-' if prefixed && !digit_seen => InvalidReason::SignAfterPrefix,
+            b'+' | b'-' if position == start => InvalidReason::DisallowedSign,
+            b'+' | b'-' => InvalidReason::MisplacedSign,
+            _ if b.is_ascii_alphanumeric() => InvalidReason::InvalidDigit,
+            _ if digit_seen => InvalidReason::TrailingCharacter,
+            _ => InvalidReason::UnexpectedCharacter,
+        }
+    }
+}
+
+impl fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        English.invalid_reason(f, *self)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{ParseOptions, StrToInt};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// Find & parse every integer literal in a string.
+///
+/// This function returns an iterator over all integer literals in
+/// `haystack`, yielding for each one its byte span within `haystack` and the
+/// result of parsing it with [`strtoint()`](crate::strtoint()).
+///
+/// A literal starts at an ASCII digit, optionally preceded by a `+` or `-`
+/// sign, that is not preceded by a letter, digit, or underscore, and it
+/// extends through all of the following letters, digits, and underscores.
+/// Thus, digits inside identifiers (e.g., the `86` and `64` in `x86_64`) are
+/// not matched, while a token that starts like a number but is not a valid
+/// integer (e.g., `42px` or `0x1G`) is yielded along with the error from
+/// parsing it.  Any positions in such errors are relative to the start of
+/// the span; use [`StrToIntError::with_offset()`] to make them relative to
+/// the start of the haystack.
+///
+/// A sign is only part of a literal if it is itself not preceded by a
+/// letter, digit, or underscore.  A sign that follows one, as in `x-5` or
+/// `5-3`, is taken to be an operator, and only the digits after it are
+/// yielded, as a positive number.
+///
+/// Note that, as periods are not part of literals, a decimal number like
+/// `1.5` is yielded as two separate integers.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::find_iter;
+///
+/// let mut iter = find_iter::<i64>("addr=0x7ffe size 4_096 errno -12 on x86_64");
+/// assert_eq!(iter.next(), Some((5..11, Ok(0x7ffe))));
+/// assert_eq!(iter.next(), Some((17..22, Ok(4096))));
+/// assert_eq!(iter.next(), Some((29..32, Ok(-12))));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// [`StrToIntError::with_offset()`]: crate::StrToIntError::with_offset()
+pub fn find_iter<T: StrToInt>(haystack: &str) -> FindIter<'_, T> {
+    find_iter_with(haystack, &ParseOptions::new())
+}
+
+/// Find & parse every integer literal in a string using the given options.
+///
+/// This is the [`ParseOptions`]-accepting equivalent of [`find_iter()`].
+/// The options are used both for parsing each literal and for deciding
+/// whether a `+` sign may start one.
+pub fn find_iter_with<'a, T: StrToInt>(
+    haystack: &'a str,
+    options: &ParseOptions,
+) -> FindIter<'a, T> {
+    FindIter {
+        haystack,
+        pos: 0,
+        options: *options,
+        _type: PhantomData,
+    }
+}
+
+/// Iterator over integer literals in a string
+///
+/// This type is returned by [`find_iter()`] and [`find_iter_with()`].
+pub struct FindIter<'a, T> {
+    haystack: &'a str,
+    pos: usize,
+    options: ParseOptions,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: StrToInt> Iterator for FindIter<'_, T> {
+    type Item = (Range<usize>, Result<T, <T as StrToInt>::Err>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.haystack.as_bytes();
+        while let Some(&b) = bytes.get(self.pos) {
+            let start = self.pos;
+            let sign = b == b'-' || (b == b'+' && self.options.plus_sign);
+            let digits_start = if sign { start + 1 } else { start };
+            if bytes.get(digits_start).is_some_and(u8::is_ascii_digit)
+                && !self.haystack[..start]
+                    .chars()
+                    .next_back()
+                    .is_some_and(is_word_char)
+            {
+                let end = self.haystack[digits_start..]
+                    .find(|c| !is_word_char(c))
+                    .map_or(self.haystack.len(), |i| i + digits_start);
+                self.pos = end;
+                let value = T::strtoint_with(&self.haystack[start..end], &self.options);
+                return Some((start..end, value));
+            }
+            self.pos += self.haystack[start..]
+                .chars()
+                .next()
+                .map_or(1, char::len_utf8);
+        }
+        None
+    }
+}
+
+impl<T: StrToInt> FusedIterator for FindIter<'_, T> {}
+
+impl<T> Clone for FindIter<'_, T> {
+    fn clone(&self) -> Self {
+        FindIter {
+            haystack: self.haystack,
+            pos: self.pos,
+            options: self.options,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for FindIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FindIter")
+            .field("haystack", &self.haystack)
+            .field("pos", &self.pos)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// Returns true if `c` can be part of a word, i.e., an identifier or an
+/// integer literal
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
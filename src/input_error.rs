@@ -0,0 +1,114 @@
+use crate::{Diagnostic, StrToIntError};
+use alloc::string::String;
+use core::fmt;
+
+/// An owned error that pairs a [`StrToIntError`] with the input that caused
+/// it and an optional label identifying where the input came from
+///
+/// `StrToIntError` does not hold on to the input string, and so, by the time
+/// it reaches a top-level error handler, it may no longer be known which value
+/// failed to parse.  An `InputError` keeps a copy of the input along with an
+/// optional label — such as the name of a command-line option, configuration
+/// key, or environment variable — that is included in its `Display` output.
+///
+/// An `InputError` is most easily created with
+/// [`StrToIntError::with_input()`].
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint;
+///
+/// let s = "0x1G";
+/// let e = strtoint::<u32>(s)
+///     .map_err(|e| e.with_input(s).with_label("max-connections"))
+///     .unwrap_err();
+/// assert_eq!(
+///     e.to_string(),
+///     "invalid value \"0x1G\" for max-connections: invalid character 'G' at position 3"
+/// );
+/// assert_eq!(e.input(), "0x1G");
+/// assert_eq!(e.label(), Some("max-connections"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct InputError {
+    input: String,
+    label: Option<String>,
+    error: StrToIntError,
+}
+
+impl InputError {
+    /// Create an `InputError` for an error that occurred while parsing
+    /// `input`
+    pub fn new<S: Into<String>>(input: S, error: StrToIntError) -> InputError {
+        InputError {
+            input: input.into(),
+            label: None,
+            error,
+        }
+    }
+
+    /// Set the label identifying where the input came from.
+    ///
+    /// Default: no label
+    #[must_use]
+    pub fn with_label<S: Into<String>>(mut self, label: S) -> InputError {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Return the input that caused the error
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Return the label identifying where the input came from, if set
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Return the underlying error
+    pub fn error(&self) -> StrToIntError {
+        self.error
+    }
+
+    /// Return a [`Diagnostic`] for displaying the underlying error along with
+    /// the input
+    pub fn diagnostic(&self) -> Diagnostic<'_> {
+        Diagnostic::new(&self.input, self.error)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value {:?}", self.input)?;
+        if let Some(label) = &self.label {
+            write!(f, " for {label}")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<InputError> for StrToIntError {
+    fn from(e: InputError) -> StrToIntError {
+        e.error
+    }
+}
+
+impl StrToIntError {
+    /// Convert the error into an owned [`InputError`] that also holds a copy
+    /// of the input that caused it
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn with_input<S: Into<String>>(self, input: S) -> InputError {
+        InputError::new(input, self)
+    }
+}
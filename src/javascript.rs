@@ -0,0 +1,97 @@
+use crate::{BasePrefix, LeadingZeros, ParseOptions, Underscores};
+
+/// The base prefixes recognized in JavaScript numeric literals
+const JS_PREFIXES: &[BasePrefix] = &[
+    BasePrefix::HEX.ignore_case(true),
+    BasePrefix::OCTAL.ignore_case(true),
+    BasePrefix::BINARY.ignore_case(true),
+];
+
+/// The base prefixes recognized in JSON5 numbers
+const JSON5_PREFIXES: &[BasePrefix] = &[BasePrefix::HEX.ignore_case(true)];
+
+impl ParseOptions {
+    /// Create a `ParseOptions` that accepts the same syntax as integers in
+    /// JSON5.
+    ///
+    /// Specifically:
+    ///
+    /// - All numbers may have a leading `+` or `-` sign.
+    ///
+    /// - The only base prefix is `0x`, matched case-insensitively.
+    ///
+    /// - A decimal number may not have leading zeros, so `0` is valid but
+    ///   `00` and `0644` are not (see [`LeadingZeros::Forbidden`]).
+    ///
+    /// - Underscores are not allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::json5();
+    /// assert_eq!(strtoint_with::<i32>("+0X1f", &opts).unwrap(), 31);
+    /// assert_eq!(strtoint_with::<i32>("-0x1F", &opts).unwrap(), -31);
+    /// assert!(strtoint_with::<i32>("0644", &opts).is_err());
+    /// assert!(strtoint_with::<i32>("0o17", &opts).is_err());
+    /// assert!(strtoint_with::<i32>("1_000", &opts).is_err());
+    /// ```
+    pub const fn json5() -> ParseOptions {
+        ParseOptions::new()
+            .prefixes(JSON5_PREFIXES)
+            .underscores(Underscores::Forbidden)
+            .leading_zeros(LeadingZeros::Forbidden)
+    }
+
+    /// Create a `ParseOptions` that accepts the same syntax as JavaScript
+    /// integer literals, both numeric and `BigInt`, in strict mode.
+    ///
+    /// Specifically:
+    ///
+    /// - A leading `+` or `-` sign is accepted, as though it were a unary
+    ///   operator applied to the literal.
+    ///
+    /// - The base prefixes `0x`, `0o`, and `0b` are matched
+    ///   case-insensitively.
+    ///
+    /// - Numeric separators follow [`Underscores::BetweenDigits`], so
+    ///   `1_000` is valid but `1__000`, `1_`, and `0x_1` are not.
+    ///
+    /// - A decimal number may not have leading zeros (see
+    ///   [`LeadingZeros::Forbidden`]).  To accept the legacy octal literals of
+    ///   non-strict JavaScript, such as `0777`, enable
+    ///   [`ParseOptions::leading_zero_octal()`] on the result.  As in
+    ///   non-strict JavaScript, a number with a leading zero and an `8` or
+    ///   `9` among its digits, such as `089`, is then read as decimal, and
+    ///   a number with a leading zero may not contain underscores (see
+    ///   [`ParseOptions::javascript_octal()`]).
+    ///
+    /// - The digits may be followed by the `BigInt` suffix `n` (see
+    ///   [`ParseOptions::bigint_suffix()`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::javascript();
+    /// assert_eq!(strtoint_with::<u64>("0x1fn", &opts).unwrap(), 31);
+    /// assert_eq!(strtoint_with::<u64>("123_456n", &opts).unwrap(), 123_456);
+    /// assert_eq!(strtoint_with::<i64>("-0B101", &opts).unwrap(), -5);
+    /// assert!(strtoint_with::<u64>("0777", &opts).is_err());
+    ///
+    /// let sloppy = ParseOptions::javascript().leading_zero_octal(true);
+    /// assert_eq!(strtoint_with::<u64>("0777", &sloppy).unwrap(), 511);
+    /// assert_eq!(strtoint_with::<u64>("089", &sloppy).unwrap(), 89);
+    /// assert!(strtoint_with::<u64>("0777n", &sloppy).is_err());
+    /// ```
+    pub const fn javascript() -> ParseOptions {
+        ParseOptions::new()
+            .prefixes(JS_PREFIXES)
+            .underscores(Underscores::BetweenDigits)
+            .leading_zeros(LeadingZeros::Forbidden)
+            .javascript_octal(true)
+            .bigint_suffix(true)
+    }
+}
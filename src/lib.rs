@@ -7,9 +7,18 @@
 //! This crate supports parsing into all primitive integer types built into
 //! Rust, along with their "`NonZero`" equivalents.
 //!
-//! If the `std` feature (enabled by default) is disabled, this crate will be
-//! built in no-std mode.  The only difference is that [`StrToIntError`] only
-//! implements the [`std::error::Error`] trait under `std`.
+//! Features
+//! ========
+//!
+//! - `std` (enabled by default) — Implements the [`std::error::Error`] trait
+//!   for [`StrToIntError`] and [`InputError`] and enables colored output for
+//!   [`Diagnostic`].  Implies `alloc`.
+//!
+//! - `alloc` — Enables the [`suggest()`] and [`suggest_with()`] functions and
+//!   the [`InputError`] type, which require allocation, and implements
+//!   [`ErrorSink`] for `Vec<StrToIntError>`.
+//!
+//! If the `std` feature is disabled, this crate will be built in no-std mode.
 //!
 //! Examples
 //! ========
@@ -30,7 +39,61 @@
 //! );
 //! assert!(strtoint::<NonZeroUsize>("0").is_err());
 //! ```
-use core::fmt;
+//!
+//! The accepted syntax can be customized by passing a [`ParseOptions`] to
+//! [`strtoint_with()`]:
+//!
+//! ```
+//! use strtoint::{strtoint_with, ParseOptions};
+//!
+//! let opts = ParseOptions::new().plus_sign(false).binary_prefix(false);
+//! assert_eq!(strtoint_with::<i32>("-0x10", &opts).unwrap(), -16);
+//! assert!(strtoint_with::<i32>("+10", &opts).is_err());
+//! assert!(strtoint_with::<i32>("0b10", &opts).is_err());
+//! ```
+
+mod base_prefix;
+mod collect;
+mod const_parse;
+mod diagnostic;
+mod error;
+mod find;
+#[cfg(feature = "alloc")]
+mod input_error;
+mod javascript;
+mod messages;
+mod options;
+mod parse;
+mod position;
+mod python;
+mod strtol;
+mod suffix;
+#[cfg(feature = "alloc")]
+mod suggest;
+mod toml;
+mod unicode;
+mod verilog;
+mod yaml;
+pub use crate::base_prefix::*;
+pub use crate::collect::*;
+pub use crate::const_parse::*;
+pub use crate::diagnostic::*;
+pub use crate::error::*;
+pub use crate::find::*;
+#[cfg(feature = "alloc")]
+pub use crate::input_error::*;
+pub use crate::messages::*;
+pub use crate::options::*;
+use crate::parse::{parse, parse_all_errors, to_nonzero, Input, Prim};
+pub use crate::position::*;
+pub use crate::strtol::*;
+pub use crate::suffix::*;
+#[cfg(feature = "alloc")]
+pub use crate::suggest::*;
+pub use crate::verilog::*;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -43,7 +106,9 @@ extern crate std;
 /// latter forbidden for unsigned types), followed by an optional base prefix
 /// (`0x`, `0o`, or `0b`, all lowercase), followed by one or more digits
 /// optionally interspersed with underscores.  Leading & trailing whitespace is
-/// not allowed.
+/// not allowed.  (Integer suffixes can be enabled via
+/// [`ParseOptions::type_suffixes()`] and [`strtoint_with()`], or see
+/// [`strtoint_typed()`].)
 ///
 /// [1]: https://doc.rust-lang.org/stable/reference/tokens.html#integer-literals
 ///
@@ -60,13 +125,307 @@ extern crate std;
 /// - The input string contains an invalid character, including surrounding or
 ///   internal whitespace, an invalid digit for the base in question, an
 ///   invalid base prefix, a sign after a base prefix, or a `-` sign for an
-///   unsigned type
+///   unsigned type; the error's [`InvalidReason`] says which of these it was
 /// - The numeric value represented by the string is outside the range of valid
 ///   values for the numeric type
+/// - The numeric type is a `NonZero` type and the value is zero
 pub fn strtoint<T: StrToInt>(s: &str) -> Result<T, <T as StrToInt>::Err> {
     T::strtoint(s)
 }
 
+/// Parse an integer from a string using the given options.
+///
+/// With the default [`ParseOptions`], this function behaves exactly the same
+/// as [`strtoint()`]; see the documentation of `ParseOptions` for the rules
+/// that can be changed.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint()`], adjusted for any rules changed by `options`.
+pub fn strtoint_with<T: StrToInt>(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<T, <T as StrToInt>::Err> {
+    T::strtoint_with(s, options)
+}
+
+/// Parse an integer from a string in the given radix.
+///
+/// This function accepts the same syntax as [`strtoint()`], except that
+/// numbers without a base prefix are parsed in radix `radix` rather than
+/// decimal.  The base prefix for `radix` (if it has one) is allowed but not
+/// required, while a base prefix for any other radix is an error.  This is
+/// equivalent to calling [`strtoint_with()`] with the following options:
+///
+/// ```
+/// # use strtoint::{ParseOptions, PrefixConflict};
+/// # let radix = 16;
+/// let opts = ParseOptions::new()
+///     .default_radix(radix)
+///     .prefix_conflict(PrefixConflict::Error);
+/// ```
+///
+/// Use `strtoint_with()` with [`ParseOptions::default_radix()`],
+/// [`ParseOptions::radix_prefix()`], and [`ParseOptions::prefix_conflict()`]
+/// to parse in a given radix with different rules for prefixes.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint()`].  In addition, a base prefix for a radix other than `radix`
+/// is an invalid character with a reason of
+/// [`InvalidReason::ConflictingPrefix`].
+///
+/// # Panics
+///
+/// Panics if `radix` is not in the range `2..=36`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint_radix;
+///
+/// assert_eq!(strtoint_radix::<u32>("ff_ff", 16).unwrap(), 0xFFFF);
+/// assert_eq!(strtoint_radix::<u32>("0xffff", 16).unwrap(), 0xFFFF);
+/// assert_eq!(strtoint_radix::<u16>("644", 8).unwrap(), 0o644);
+/// assert_eq!(strtoint_radix::<i64>("-zz", 36).unwrap(), -1295);
+/// assert!(strtoint_radix::<u32>("0b101", 16).is_err());
+/// assert!(strtoint_radix::<u32>("19", 8).is_err());
+/// ```
+pub fn strtoint_radix<T: StrToInt>(s: &str, radix: u32) -> Result<T, <T as StrToInt>::Err> {
+    let options = ParseOptions::new()
+        .default_radix(radix)
+        .prefix_conflict(PrefixConflict::Error);
+    T::strtoint_with(s, &options)
+}
+
+/// Parse an integer from a byte slice.
+///
+/// This function accepts the same syntax as [`strtoint()`], but it takes its
+/// input as a slice of bytes, each of which is interpreted as an ASCII
+/// character.  When an invalid byte is encountered, it is reported as a
+/// [`StrToIntError::InvalidCharacter`] error in which `c` is the byte
+/// converted to a `char` (so that non-ASCII bytes become characters in the
+/// range U+0080 through U+00FF) and `position` is the byte's offset in the
+/// input.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint()`].  In addition, any non-ASCII byte is always an invalid
+/// character.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_bytes, InvalidReason, StrToIntError};
+///
+/// assert_eq!(strtoint_bytes::<u16>(b"0xBEEF").unwrap(), 0xBEEF);
+/// assert_eq!(
+///     strtoint_bytes::<u16>(b"12\xFF").unwrap_err(),
+///     StrToIntError::InvalidCharacter {
+///         c: '\u{FF}',
+///         position: 2,
+///         reason: InvalidReason::TrailingCharacter,
+///     }
+/// );
+/// ```
+pub fn strtoint_bytes<T: StrToInt>(s: &[u8]) -> Result<T, <T as StrToInt>::Err> {
+    T::strtoint_bytes(s)
+}
+
+/// Parse an integer from a byte slice using the given options.
+///
+/// This is the byte-slice equivalent of [`strtoint_with()`]; see
+/// [`strtoint_bytes()`] for how invalid bytes are reported.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_with()`].  In addition, any non-ASCII byte is always an invalid
+/// character.
+pub fn strtoint_bytes_with<T: StrToInt>(
+    s: &[u8],
+    options: &ParseOptions,
+) -> Result<T, <T as StrToInt>::Err> {
+    T::strtoint_bytes_with(s, options)
+}
+
+/// Parse an integer from a string using the given options, returning the
+/// value and whether it overflowed.
+///
+/// This function behaves the same as [`strtoint_with()`], except that it also
+/// returns a `bool` indicating whether the numeric value of the string was
+/// outside the range of the numeric type and so was clamped or wrapped in
+/// accordance with [`ParseOptions::overflow()`].  (If the overflow mode is
+/// [`Overflow::Error`], the `bool` is always `false`, as out-of-range values
+/// produce errors instead.)
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_with()`].
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_overflowing_with, Overflow, ParseOptions};
+///
+/// let saturate = ParseOptions::new().overflow(Overflow::Saturate);
+/// assert_eq!(strtoint_overflowing_with::<u8>("300", &saturate).unwrap(), (255, true));
+/// assert_eq!(strtoint_overflowing_with::<i8>("-300", &saturate).unwrap(), (-128, true));
+/// assert_eq!(strtoint_overflowing_with::<u8>("200", &saturate).unwrap(), (200, false));
+///
+/// let wrap = ParseOptions::new().overflow(Overflow::Wrap);
+/// assert_eq!(strtoint_overflowing_with::<u8>("0x1FF", &wrap).unwrap(), (255, true));
+/// assert_eq!(strtoint_overflowing_with::<i8>("128", &wrap).unwrap(), (-128, true));
+/// ```
+pub fn strtoint_overflowing_with<T: StrToInt>(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<(T, bool), <T as StrToInt>::Err> {
+    T::strtoint_overflowing_with(s, options)
+}
+
+/// Parse an integer from the start of a string, returning the value and the
+/// rest of the string.
+///
+/// This function parses the longest prefix of `s` that is a valid integer
+/// according to the same rules as [`strtoint()`], and it returns the parsed
+/// value along with the unparsed remainder of `s`.  If a base prefix is not
+/// followed by any digits, only the leading `0` is parsed, and the remainder
+/// starts at the prefix letter.
+///
+/// To get the number of bytes consumed instead of the remainder, use
+/// [`StrToInt::strtoint_prefix()`].
+///
+/// # Errors
+///
+/// This function will return an error if no digits at all could be parsed
+/// from the start of the string, in which case the error is the same as what
+/// [`strtoint()`] would return for the whole string, or if the numeric value
+/// of the parsed prefix is outside the range of valid values for the numeric
+/// type.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint_prefix;
+///
+/// assert_eq!(strtoint_prefix::<u32>("0x1F,rest").unwrap(), (31, ",rest"));
+/// assert_eq!(strtoint_prefix::<i32>("-42px").unwrap(), (-42, "px"));
+/// assert_eq!(strtoint_prefix::<u8>("0xyz").unwrap(), (0, "xyz"));
+/// assert_eq!(strtoint_prefix::<u8>("7").unwrap(), (7, ""));
+/// assert!(strtoint_prefix::<u8>("px").is_err());
+/// assert!(strtoint_prefix::<u8>("300px").is_err());
+/// ```
+pub fn strtoint_prefix<T: StrToInt>(s: &str) -> Result<(T, &str), <T as StrToInt>::Err> {
+    strtoint_prefix_with(s, &ParseOptions::new())
+}
+
+/// Parse an integer from the start of a string using the given options,
+/// returning the value and the rest of the string.
+///
+/// This is the [`ParseOptions`]-accepting equivalent of [`strtoint_prefix()`].
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_prefix()`], adjusted for any rules changed by `options`.
+pub fn strtoint_prefix_with<'a, T: StrToInt>(
+    s: &'a str,
+    options: &ParseOptions,
+) -> Result<(T, &'a str), <T as StrToInt>::Err> {
+    let (value, len) = T::strtoint_prefix_with(s, options)?;
+    Ok((value, &s[len..]))
+}
+
+/// Parse an integer from the start of a string using the given options,
+/// returning the value, the rest of the string, and whether the value
+/// overflowed.
+///
+/// This function behaves the same as [`strtoint_prefix_with()`], except that
+/// it also returns a `bool` indicating whether the value was clamped or
+/// wrapped in accordance with [`ParseOptions::overflow()`], as for
+/// [`strtoint_overflowing_with()`].  The rest of the string starts after all
+/// of the digits, including those past the point of overflow.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_prefix_with()`].
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_prefix_overflowing_with, Overflow, ParseOptions};
+///
+/// let saturate = ParseOptions::new().overflow(Overflow::Saturate);
+/// assert_eq!(
+///     strtoint_prefix_overflowing_with::<u8>("300px", &saturate).unwrap(),
+///     (255, "px", true)
+/// );
+/// assert_eq!(
+///     strtoint_prefix_overflowing_with::<u8>("200px", &saturate).unwrap(),
+///     (200, "px", false)
+/// );
+/// ```
+pub fn strtoint_prefix_overflowing_with<'a, T: StrToInt>(
+    s: &'a str,
+    options: &ParseOptions,
+) -> Result<(T, &'a str, bool), <T as StrToInt>::Err> {
+    let (value, len, overflowed) = T::strtoint_prefix_overflowing_with(s, options)?;
+    Ok((value, &s[len..], overflowed))
+}
+
+/// Parse an integer from the start of a string and advance the string past
+/// it.
+///
+/// This function parses the same prefix of `*s` as [`strtoint_prefix()`].  On
+/// success, `*s` is updated to point to the unparsed remainder; on failure,
+/// `*s` is left unchanged.  This makes it easy to chain calls in a
+/// hand-written parser.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_prefix()`].
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint_advance;
+///
+/// let mut s = "0x10,-0b11";
+/// assert_eq!(strtoint_advance::<i32>(&mut s).unwrap(), 16);
+/// s = s.strip_prefix(',').unwrap();
+/// assert_eq!(strtoint_advance::<i32>(&mut s).unwrap(), -3);
+/// assert_eq!(s, "");
+/// ```
+pub fn strtoint_advance<T: StrToInt>(s: &mut &str) -> Result<T, <T as StrToInt>::Err> {
+    strtoint_advance_with(s, &ParseOptions::new())
+}
+
+/// Parse an integer from the start of a string using the given options and
+/// advance the string past it.
+///
+/// This is the [`ParseOptions`]-accepting equivalent of
+/// [`strtoint_advance()`].
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint_prefix()`], adjusted for any rules changed by `options`.
+pub fn strtoint_advance_with<T: StrToInt>(
+    s: &mut &str,
+    options: &ParseOptions,
+) -> Result<T, <T as StrToInt>::Err> {
+    let (value, rest) = strtoint_prefix_with(s, options)?;
+    *s = rest;
+    Ok(value)
+}
+
 /// Trait used to implement the [`strtoint()`] function
 ///
 /// Call [`strtoint()`] instead of using this trait directly.  You only ever
@@ -77,115 +436,176 @@ pub trait StrToInt {
 
     /// Parse a string as the type in question
     fn strtoint(s: &str) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Self::strtoint_with(s, &ParseOptions::new())
+    }
+
+    /// Parse a string as the type in question using the given options
+    fn strtoint_with(s: &str, options: &ParseOptions) -> Result<Self, Self::Err>
     where
         Self: Sized;
-}
 
-/// Error type for the [`strtoint()`] function
-///
-/// This type is used as the error type for [`strtoint()`] and [`StrToInt`] for
-/// all types covered by this crate.
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub enum StrToIntError {
-    /// Returned when the input string contained no digits
-    NoDigits,
-    /// Returned when the input string contained an invalid character; `c` is
-    /// the character in question, and `position` is its index in the input
-    InvalidCharacter { c: char, position: usize },
-    /// Returned when the numeric value of the input string was out of range
-    /// for the numeric type
-    OutOfRange,
-}
-
-impl fmt::Display for StrToIntError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            StrToIntError::NoDigits => write!(f, "no digits in input"),
-            StrToIntError::InvalidCharacter { c, position } => {
-                write!(f, "invalid character {c:?} at position {position}")
-            }
-            StrToIntError::OutOfRange => write!(f, "value is out of range for numeric type"),
-        }
+    /// Parse a byte slice as the type in question
+    fn strtoint_bytes(s: &[u8]) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Self::strtoint_bytes_with(s, &ParseOptions::new())
     }
-}
 
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl std::error::Error for StrToIntError {}
+    /// Parse a byte slice as the type in question using the given options
+    fn strtoint_bytes_with(s: &[u8], options: &ParseOptions) -> Result<Self, Self::Err>
+    where
+        Self: Sized;
+
+    /// Parse the longest prefix of a string that is a valid integer as the
+    /// type in question, returning the value and the length in bytes of the
+    /// prefix
+    fn strtoint_prefix(s: &str) -> Result<(Self, usize), Self::Err>
+    where
+        Self: Sized,
+    {
+        Self::strtoint_prefix_with(s, &ParseOptions::new())
+    }
+
+    /// Parse the longest prefix of a string that is a valid integer as the
+    /// type in question using the given options, returning the value and the
+    /// length in bytes of the prefix
+    fn strtoint_prefix_with(s: &str, options: &ParseOptions) -> Result<(Self, usize), Self::Err>
+    where
+        Self: Sized;
+
+    /// Parse a string as the type in question using the given options,
+    /// returning the value and whether it was clamped or wrapped in
+    /// accordance with [`ParseOptions::overflow()`]
+    ///
+    /// The default implementation calls [`StrToInt::strtoint_with()`] and
+    /// always reports that the value was not clamped or wrapped; implementors
+    /// that support [`Overflow::Saturate`] or [`Overflow::Wrap`] should
+    /// override it.
+    fn strtoint_overflowing_with(s: &str, options: &ParseOptions) -> Result<(Self, bool), Self::Err>
+    where
+        Self: Sized,
+    {
+        Self::strtoint_with(s, options).map(|value| (value, false))
+    }
+
+    /// Parse the longest prefix of a string that is a valid integer as the
+    /// type in question using the given options, returning the value, the
+    /// length in bytes of the prefix, and whether the value was clamped or
+    /// wrapped in accordance with [`ParseOptions::overflow()`]
+    ///
+    /// The default implementation calls [`StrToInt::strtoint_prefix_with()`]
+    /// and always reports that the value was not clamped or wrapped;
+    /// implementors that support [`Overflow::Saturate`] or [`Overflow::Wrap`]
+    /// should override it.
+    fn strtoint_prefix_overflowing_with(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize, bool), Self::Err>
+    where
+        Self: Sized,
+    {
+        Self::strtoint_prefix_with(s, options).map(|(value, len)| (value, len, false))
+    }
+
+    /// Parse a string as the type in question using the given options,
+    /// pushing every error found in the string to `errors`; see
+    /// [`strtoint_all_errors()`] for details
+    ///
+    /// The default implementation calls [`StrToInt::strtoint_with()`] and
+    /// pushes nothing to `errors`, as `Self::Err` need not be a
+    /// [`StrToIntError`].
+    fn strtoint_all_errors_with(
+        s: &str,
+        options: &ParseOptions,
+        errors: &mut dyn ErrorSink,
+    ) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        let _ = errors;
+        Self::strtoint_with(s, options)
+    }
+}
 
 macro_rules! impl_prim {
-    ($($t:ty),* $(,)?) => {
+    ($($t:ty => $suffix:ident),* $(,)?) => {
       $(
+        impl Prim for $t {
+            const SIGNED: bool = <$t>::MIN != 0;
+            const MAX_MAGNITUDE: u128 = <$t>::MAX as u128;
+            const MIN_MAGNITUDE: u128 = (<$t>::MIN as i128).unsigned_abs();
+            const SUFFIX: IntSuffix = IntSuffix::$suffix;
+
+            fn from_magnitude(negative: bool, magnitude: u128) -> Self {
+                let value = magnitude as $t;
+                if negative {
+                    value.wrapping_neg()
+                } else {
+                    value
+                }
+            }
+        }
+
         impl StrToInt for $t {
             type Err = StrToIntError;
 
-            fn strtoint(mut s: &str) -> Result<Self, Self::Err>
+            fn strtoint_with(s: &str, options: &ParseOptions) -> Result<Self, Self::Err>
             where
                 Self: Sized,
             {
-                let mut offset = 0;
-                let is_positive = {
-                    if let Some(t) = s.strip_prefix('+') {
-                        offset += 1;
-                        s = t;
-                        true
-                    } else if let Some(t) = s.strip_prefix('-') {
-                        if <$t>::MIN == 0 {
-                            return Err(StrToIntError::InvalidCharacter {c: '-', position: 0});
-                        }
-                        offset += 1;
-                        s = t;
-                        false
-                    } else {
-                        true
-                    }
-                };
-                let radix = {
-                    if let Some(t) = s.strip_prefix("0x") {
-                        offset += 2;
-                        s = t;
-                        16
-                    } else if let Some(t) = s.strip_prefix("0o") {
-                        offset += 2;
-                        s = t;
-                        8
-                    } else if let Some(t) = s.strip_prefix("0b") {
-                        offset += 2;
-                        s = t;
-                        2
-                    } else {
-                        10
-                    }
-                };
-                let mut value: $t = 0;
-                let mut digit_seen = false;
-                for (i, c) in s.char_indices() {
-                    if c == '_' {
-                        if !digit_seen && radix == 10 {
-                            return Err(StrToIntError::InvalidCharacter{c, position: i + offset});
-                        }
-                        continue;
-                    }
-                    let digit = c
-                        .to_digit(radix)
-                        .ok_or_else(|| StrToIntError::InvalidCharacter {
-                            c,
-                            position: i + offset,
-                        })?;
-                    value = value
-                        .checked_mul(radix as $t)
-                        .ok_or(StrToIntError::OutOfRange)?;
-                    value = if is_positive {
-                        value.checked_add(digit as $t)
-                    } else {
-                        value.checked_sub(digit as $t)
-                    }.ok_or(StrToIntError::OutOfRange)?;
-                    digit_seen = true;
-                }
-                if !digit_seen {
-                    return Err(StrToIntError::NoDigits);
-                }
-                Ok(value)
+                parse::<$t>(Input::Str(s), options, false).map(|p| p.value)
+            }
+
+            fn strtoint_bytes_with(s: &[u8], options: &ParseOptions) -> Result<Self, Self::Err>
+            where
+                Self: Sized,
+            {
+                parse::<$t>(Input::Bytes(s), options, false).map(|p| p.value)
+            }
+
+            fn strtoint_prefix_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, usize), Self::Err>
+            where
+                Self: Sized,
+            {
+                parse::<$t>(Input::Str(s), options, true).map(|p| (p.value, p.len))
+            }
+
+            fn strtoint_overflowing_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, bool), Self::Err>
+            where
+                Self: Sized,
+            {
+                parse::<$t>(Input::Str(s), options, false).map(|p| (p.value, p.overflowed))
+            }
+
+            fn strtoint_prefix_overflowing_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, usize, bool), Self::Err>
+            where
+                Self: Sized,
+            {
+                parse::<$t>(Input::Str(s), options, true).map(|p| (p.value, p.len, p.overflowed))
+            }
+
+            fn strtoint_all_errors_with(
+                s: &str,
+                options: &ParseOptions,
+                errors: &mut dyn ErrorSink,
+            ) -> Result<Self, Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_all_errors::<$t>(Input::Str(s), options, errors).map(|p| p.value)
             }
         }
       )*
@@ -197,18 +617,104 @@ macro_rules! impl_nonzero {
         impl StrToInt for $t {
             type Err = StrToIntError;
 
-            fn strtoint(s: &str) -> Result<Self, Self::Err>
+            fn strtoint_with(s: &str, options: &ParseOptions) -> Result<Self, Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_nonzero!($t, $inner, Input::Str(s), options, false).map(|p| p.value)
+            }
+
+            fn strtoint_bytes_with(s: &[u8], options: &ParseOptions) -> Result<Self, Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_nonzero!($t, $inner, Input::Bytes(s), options, false).map(|p| p.value)
+            }
+
+            fn strtoint_prefix_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, usize), Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_nonzero!($t, $inner, Input::Str(s), options, true).map(|p| (p.value, p.len))
+            }
+
+            fn strtoint_overflowing_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, bool), Self::Err>
             where
                 Self: Sized,
             {
-                let value = <$inner>::strtoint(s)?;
-                <$t>::new(value).ok_or(StrToIntError::OutOfRange)
+                parse_nonzero!($t, $inner, Input::Str(s), options, false)
+                    .map(|p| (p.value, p.overflowed))
             }
+
+            fn strtoint_prefix_overflowing_with(
+                s: &str,
+                options: &ParseOptions,
+            ) -> Result<(Self, usize, bool), Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_nonzero!($t, $inner, Input::Str(s), options, true)
+                    .map(|p| (p.value, p.len, p.overflowed))
+            }
+
+            fn strtoint_all_errors_with(
+                s: &str,
+                options: &ParseOptions,
+                errors: &mut dyn ErrorSink,
+            ) -> Result<Self, Self::Err>
+            where
+                Self: Sized,
+            {
+                parse_all_errors::<$inner>(Input::Str(s), options, errors)
+                    .and_then(|p| {
+                        to_nonzero(p, options, <$t>::new, nonzero_one!($t)).map_err(|e| {
+                            errors.push(e);
+                            e
+                        })
+                    })
+                    .map(|p| p.value)
+            }
+        }
+    };
+}
+
+macro_rules! parse_nonzero {
+    ($t:ty, $inner:ty, $input:expr, $options:expr, $partial:expr) => {
+        parse::<$inner>($input, $options, $partial)
+            .and_then(|p| to_nonzero(p, $options, <$t>::new, nonzero_one!($t)))
+    };
+}
+
+/// The value one as the `NonZero` type `$t`
+macro_rules! nonzero_one {
+    ($t:ty) => {
+        match <$t>::new(1) {
+            Some(one) => one,
+            None => unreachable!(),
         }
     };
 }
 
-impl_prim!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_prim!(
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    isize => Isize,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    usize => Usize,
+);
 impl_nonzero!(core::num::NonZeroI8, i8);
 impl_nonzero!(core::num::NonZeroI16, i16);
 impl_nonzero!(core::num::NonZeroI32, i32);
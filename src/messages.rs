@@ -0,0 +1,266 @@
+use crate::{IntSuffix, InvalidReason, OutOfRangeKind, StrToIntError};
+use core::fmt;
+
+/// A provider of the messages used to display a [`StrToIntError`]
+///
+/// Most methods of this trait write the message for one variant of
+/// `StrToIntError`, given that variant's fields; the others write the
+/// description of an [`InvalidReason`] and the label shown under the input
+/// in a [`Diagnostic`].  Each method has a default implementation that writes
+/// the same English text as the corresponding `Display` implementation.  An
+/// application can translate error messages by implementing this trait for a
+/// type of its own, overriding whichever methods it needs, and displaying
+/// errors via [`StrToIntError::localized()`] or
+/// [`Diagnostic::localized()`].  As new variants are added to
+/// `StrToIntError`, new methods with default implementations will be added
+/// to this trait as well.
+///
+/// # Example
+///
+/// ```
+/// use core::fmt;
+/// use strtoint::{strtoint, ErrorMessages, InvalidReason};
+///
+/// struct French;
+///
+/// impl ErrorMessages for French {
+///     fn no_digits(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "aucun chiffre dans l'entrée")
+///     }
+///
+///     fn invalid_character(
+///         &self,
+///         f: &mut fmt::Formatter<'_>,
+///         c: char,
+///         position: usize,
+///         _reason: InvalidReason,
+///     ) -> fmt::Result {
+///         write!(f, "caractère {c:?} invalide à la position {position}")
+///     }
+/// }
+///
+/// let e = strtoint::<u32>("0x1G").unwrap_err();
+/// assert_eq!(
+///     e.localized(&French).to_string(),
+///     "caractère 'G' invalide à la position 3"
+/// );
+/// let e = strtoint::<u32>("").unwrap_err();
+/// assert_eq!(e.localized(&French).to_string(), "aucun chiffre dans l'entrée");
+///
+/// // Methods that are not overridden fall back to English:
+/// let e = strtoint::<u8>("256").unwrap_err();
+/// assert_eq!(
+///     e.localized(&French).to_string(),
+///     "value is too large for numeric type (maximum: 255)"
+/// );
+/// ```
+///
+/// [`Diagnostic`]: crate::Diagnostic
+/// [`Diagnostic::localized()`]: crate::Diagnostic::localized()
+pub trait ErrorMessages {
+    /// Write the message for [`StrToIntError::NoDigits`]
+    fn no_digits(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no digits in input")
+    }
+
+    /// Write the message for [`StrToIntError::InvalidCharacter`]
+    fn invalid_character(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        c: char,
+        position: usize,
+        reason: InvalidReason,
+    ) -> fmt::Result {
+        let _ = reason;
+        write!(f, "invalid character {c:?} at position {position}")
+    }
+
+    /// Write the message for [`StrToIntError::OutOfRange`]
+    fn out_of_range(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        kind: OutOfRangeKind,
+        min: i128,
+        max: u128,
+        position: usize,
+    ) -> fmt::Result {
+        let _ = position;
+        match kind {
+            OutOfRangeKind::TooLarge => {
+                write!(f, "value is too large for numeric type (maximum: {max})")
+            }
+            OutOfRangeKind::TooSmall => {
+                write!(f, "value is too small for numeric type (minimum: {min})")
+            }
+        }
+    }
+
+    /// Write the message for [`StrToIntError::SuffixMismatch`]
+    fn suffix_mismatch(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        suffix: IntSuffix,
+        position: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "integer suffix {:?} at position {position} does not match numeric type",
+            suffix.as_str()
+        )
+    }
+
+    /// Write the message for [`StrToIntError::InvalidRadix`]
+    fn invalid_radix(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        radix: u32,
+        position: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "radix {radix} at position {position} is not between 2 and 36"
+        )
+    }
+
+    /// Write the message for [`StrToIntError::ExceedsWidth`]
+    fn exceeds_width(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        width: u32,
+        position: usize,
+    ) -> fmt::Result {
+        let _ = position;
+        write!(f, "value does not fit in declared width of {width} bits")
+    }
+
+    /// Write the message for [`StrToIntError::UnknownDigit`]
+    fn unknown_digit(&self, f: &mut fmt::Formatter<'_>, c: char, position: usize) -> fmt::Result {
+        write!(
+            f,
+            "unknown or high-impedance digit {c:?} at position {position}"
+        )
+    }
+
+    /// Write the message for [`StrToIntError::Zero`]
+    fn zero(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is zero, but numeric type is nonzero")
+    }
+
+    /// Write the description of an [`InvalidReason`], as used by its
+    /// `Display` implementation and in the labels of a [`Diagnostic`]
+    ///
+    /// [`Diagnostic`]: crate::Diagnostic
+    fn invalid_reason(&self, f: &mut fmt::Formatter<'_>, reason: InvalidReason) -> fmt::Result {
+        let s = match reason {
+            InvalidReason::UnsignedMinus => "minus sign for unsigned type",
+            InvalidReason::DisallowedSign => "sign not allowed",
+            InvalidReason::SignAfterPrefix => "sign after base prefix",
+            InvalidReason::MisplacedSign => "sign not at start of input",
+            InvalidReason::LeadingUnderscore => "underscore before first digit",
+            InvalidReason::MisplacedUnderscore => "underscore not allowed here",
+            InvalidReason::InvalidDigit => "not a valid digit for the radix",
+            InvalidReason::UnexpectedCharacter => "unexpected character before digits",
+            InvalidReason::TrailingCharacter => "unexpected character after digits",
+            InvalidReason::InvalidSuffix => "unrecognized integer type suffix",
+            InvalidReason::DisallowedBigIntSuffix => "BigInt suffix not allowed after leading zero",
+            InvalidReason::MissingPrefix => "expected base prefix",
+            InvalidReason::ConflictingPrefix => "base prefix does not match radix",
+            InvalidReason::NoLeadingDigit => "number must start with a decimal digit",
+            InvalidReason::UnclosedHash => "missing closing '#'",
+            InvalidReason::ZeroWidth => "width must be greater than zero",
+            InvalidReason::WidthTooLarge => "width is too large",
+            InvalidReason::LeadingZero => "leading zeros are not allowed",
+            InvalidReason::InvalidSexagesimal => "base-60 component must be from 0 to 59",
+        };
+        f.write_str(s)
+    }
+
+    /// Write the label shown next to the markers under the input in a
+    /// [`Diagnostic`] for `error`.  The default implementation uses
+    /// [`ErrorMessages::invalid_reason()`] for
+    /// [`StrToIntError::InvalidCharacter`].
+    ///
+    /// [`Diagnostic`]: crate::Diagnostic
+    fn diagnostic_label(&self, f: &mut fmt::Formatter<'_>, error: StrToIntError) -> fmt::Result {
+        let s = match error {
+            StrToIntError::NoDigits => "expected digits",
+            StrToIntError::InvalidCharacter { reason, .. } => {
+                return self.invalid_reason(f, reason);
+            }
+            StrToIntError::OutOfRange { .. } => "value goes out of range here",
+            StrToIntError::SuffixMismatch { .. } => "suffix does not match numeric type",
+            StrToIntError::InvalidRadix { .. } => "radix must be from 2 to 36",
+            StrToIntError::ExceedsWidth { .. } => "value exceeds declared width here",
+            StrToIntError::UnknownDigit { .. } => "unknown or high-impedance digit",
+            StrToIntError::Zero => "value is zero",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The default [`ErrorMessages`] provider, used by the `Display`
+/// implementation of [`StrToIntError`]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct English;
+
+impl ErrorMessages for English {}
+
+/// A [`StrToIntError`] displayed using the messages from an [`ErrorMessages`]
+/// provider
+///
+/// This type is returned by [`StrToIntError::localized()`].
+pub struct Localized<'a, M: ?Sized> {
+    error: StrToIntError,
+    messages: &'a M,
+}
+
+impl<M: ?Sized> fmt::Debug for Localized<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localized")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M: ErrorMessages + ?Sized> fmt::Display for Localized<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error {
+            StrToIntError::NoDigits => self.messages.no_digits(f),
+            StrToIntError::InvalidCharacter {
+                c,
+                position,
+                reason,
+            } => self.messages.invalid_character(f, c, position, reason),
+            StrToIntError::OutOfRange {
+                kind,
+                min,
+                max,
+                position,
+            } => self.messages.out_of_range(f, kind, min, max, position),
+            StrToIntError::SuffixMismatch { suffix, position } => {
+                self.messages.suffix_mismatch(f, suffix, position)
+            }
+            StrToIntError::InvalidRadix { radix, position } => {
+                self.messages.invalid_radix(f, radix, position)
+            }
+            StrToIntError::ExceedsWidth { width, position } => {
+                self.messages.exceeds_width(f, width, position)
+            }
+            StrToIntError::UnknownDigit { c, position } => {
+                self.messages.unknown_digit(f, c, position)
+            }
+            StrToIntError::Zero => self.messages.zero(f),
+        }
+    }
+}
+
+impl StrToIntError {
+    /// Return a value that displays the error using the messages provided by
+    /// `messages`; see [`ErrorMessages`] for an example
+    pub fn localized<'a, M: ErrorMessages + ?Sized>(&self, messages: &'a M) -> Localized<'a, M> {
+        Localized {
+            error: *self,
+            messages,
+        }
+    }
+}
@@ -0,0 +1,822 @@
+use crate::BasePrefix;
+
+/// Options for customizing the integer syntax accepted by [`strtoint_with()`]
+/// and [`StrToInt::strtoint_with()`]
+///
+/// A new `ParseOptions` (as returned by [`ParseOptions::new()`] or
+/// [`Default::default()`]) accepts exactly the same syntax as [`strtoint()`];
+/// the builder methods can then be used to turn individual rules on or off.
+///
+/// [`strtoint()`]: crate::strtoint()
+/// [`strtoint_with()`]: crate::strtoint_with()
+/// [`StrToInt::strtoint_with()`]: crate::StrToInt::strtoint_with()
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_with, ParseOptions, Underscores};
+///
+/// let opts = ParseOptions::new()
+///     .hex_prefix(false)
+///     .underscores(Underscores::Forbidden);
+/// assert_eq!(strtoint_with::<u32>("0o17", &opts).unwrap(), 15);
+/// assert!(strtoint_with::<u32>("0x17", &opts).is_err());
+/// assert!(strtoint_with::<u32>("1_000", &opts).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    pub(crate) leading_whitespace: bool,
+    pub(crate) trailing_whitespace: bool,
+    pub(crate) unicode_whitespace: bool,
+    pub(crate) plus_sign: bool,
+    pub(crate) signed_prefixes: bool,
+    pub(crate) unsigned_minus: bool,
+    pub(crate) negate_unsigned: bool,
+    pub(crate) hex_prefix: bool,
+    pub(crate) octal_prefix: bool,
+    pub(crate) binary_prefix: bool,
+    pub(crate) prefixes: Option<&'static [BasePrefix]>,
+    pub(crate) default_radix: u32,
+    pub(crate) leading_zero_octal: bool,
+    pub(crate) javascript_octal: bool,
+    pub(crate) radix_prefix: PrefixRule,
+    pub(crate) radix_suffixes: bool,
+    pub(crate) hash_radix: HashRadix,
+    pub(crate) verilog_literals: bool,
+    pub(crate) prefix_conflict: PrefixConflict,
+    pub(crate) underscores: Underscores,
+    pub(crate) leading_zeros: LeadingZeros,
+    pub(crate) unicode_digits: bool,
+    pub(crate) sexagesimal: bool,
+    pub(crate) type_suffixes: bool,
+    pub(crate) bigint_suffix: bool,
+    pub(crate) overflow: Overflow,
+}
+
+impl ParseOptions {
+    /// Create a new `ParseOptions` that accepts the same syntax as
+    /// [`strtoint()`](crate::strtoint())
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            leading_whitespace: false,
+            trailing_whitespace: false,
+            unicode_whitespace: false,
+            plus_sign: true,
+            signed_prefixes: true,
+            unsigned_minus: false,
+            negate_unsigned: false,
+            hex_prefix: true,
+            octal_prefix: true,
+            binary_prefix: true,
+            prefixes: None,
+            default_radix: 10,
+            leading_zero_octal: false,
+            javascript_octal: false,
+            radix_prefix: PrefixRule::Optional,
+            radix_suffixes: false,
+            hash_radix: HashRadix::Disabled,
+            verilog_literals: false,
+            prefix_conflict: PrefixConflict::Override,
+            underscores: Underscores::Rust,
+            leading_zeros: LeadingZeros::Allowed,
+            unicode_digits: false,
+            sexagesimal: false,
+            type_suffixes: false,
+            bigint_suffix: false,
+            overflow: Overflow::Error,
+        }
+    }
+
+    /// Set whether leading whitespace is skipped.  Whitespace consists of the
+    /// characters recognized by C's `isspace()` in the "C" locale: space,
+    /// tab, line feed, vertical tab, form feed, and carriage return.  See
+    /// also [`ParseOptions::unicode_whitespace()`].
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn leading_whitespace(mut self, yes: bool) -> ParseOptions {
+        self.leading_whitespace = yes;
+        self
+    }
+
+    /// Set whether trailing whitespace is skipped when parsing a whole
+    /// string.  Whitespace is as for [`ParseOptions::leading_whitespace()`].
+    /// When parsing only the start of a string, as by
+    /// [`strtoint_prefix_with()`](crate::strtoint_prefix_with()), trailing
+    /// whitespace is left unparsed.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn trailing_whitespace(mut self, yes: bool) -> ParseOptions {
+        self.trailing_whitespace = yes;
+        self
+    }
+
+    /// Set whether the whitespace skipped by
+    /// [`ParseOptions::leading_whitespace()`] and
+    /// [`ParseOptions::trailing_whitespace()`] also includes the other
+    /// characters that Python's `str.isspace()` recognizes: the ASCII
+    /// information separators U+001C through U+001F and non-ASCII whitespace
+    /// such as U+00A0 NO-BREAK SPACE and U+3000 IDEOGRAPHIC SPACE.  These
+    /// characters are never recognized as whitespace in byte input.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn unicode_whitespace(mut self, yes: bool) -> ParseOptions {
+        self.unicode_whitespace = yes;
+        self
+    }
+
+    /// Set whether a leading `+` sign is accepted.
+    ///
+    /// Default: `true`
+    #[must_use]
+    pub const fn plus_sign(mut self, yes: bool) -> ParseOptions {
+        self.plus_sign = yes;
+        self
+    }
+
+    /// Set whether a sign may precede a base prefix, as in `-0x10`.
+    ///
+    /// When this is `false`, the sign of a number with a base prefix is
+    /// reported as an invalid character with a reason of
+    /// [`InvalidReason::DisallowedSign`].  Numbers without a base prefix may
+    /// still be signed.
+    ///
+    /// Default: `true`
+    ///
+    /// [`InvalidReason::DisallowedSign`]: crate::InvalidReason::DisallowedSign
+    #[must_use]
+    pub const fn signed_prefixes(mut self, yes: bool) -> ParseOptions {
+        self.signed_prefixes = yes;
+        self
+    }
+
+    /// Set whether a leading `-` sign is accepted when parsing an unsigned
+    /// type.
+    ///
+    /// When this is `false`, a `-` sign for an unsigned type is reported as an
+    /// invalid character.  When this is `true`, the sign is accepted, and the
+    /// resulting value is out of range unless it equals zero (e.g., `"-0"`).
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn unsigned_minus(mut self, yes: bool) -> ParseOptions {
+        self.unsigned_minus = yes;
+        self
+    }
+
+    /// Set whether a leading `-` sign for an unsigned type negates the value
+    /// modulo 2<sup>N</sup>, where N is the number of bits in the type, as
+    /// C's `strtoul()` does.
+    ///
+    /// When this is `true`, the sign is accepted regardless of
+    /// [`ParseOptions::unsigned_minus()`], the magnitude after the sign is
+    /// checked against the type's maximum value, and the result is the
+    /// two's-complement negation of the magnitude, so that `"-1"` parses as
+    /// `u8::MAX`.  If the magnitude is out of range, the error is that the
+    /// value is too large, and [`Overflow::Saturate`] clamps the result to
+    /// the type's maximum.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().negate_unsigned(true);
+    /// assert_eq!(strtoint_with::<u8>("-1", &opts).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<u8>("-255", &opts).unwrap(), 1);
+    /// assert!(strtoint_with::<u8>("-256", &opts).is_err());
+    /// assert_eq!(strtoint_with::<i8>("-128", &opts).unwrap(), -128);
+    /// ```
+    #[must_use]
+    pub const fn negate_unsigned(mut self, yes: bool) -> ParseOptions {
+        self.negate_unsigned = yes;
+        self
+    }
+
+    /// Set whether the hexadecimal prefix `0x` is recognized.
+    ///
+    /// This setting has no effect if a custom table of prefixes has been set
+    /// with [`ParseOptions::prefixes()`].
+    ///
+    /// Default: `true`
+    #[must_use]
+    pub const fn hex_prefix(mut self, yes: bool) -> ParseOptions {
+        self.hex_prefix = yes;
+        self
+    }
+
+    /// Set whether the octal prefix `0o` is recognized.
+    ///
+    /// This setting has no effect if a custom table of prefixes has been set
+    /// with [`ParseOptions::prefixes()`].
+    ///
+    /// Default: `true`
+    #[must_use]
+    pub const fn octal_prefix(mut self, yes: bool) -> ParseOptions {
+        self.octal_prefix = yes;
+        self
+    }
+
+    /// Set whether the binary prefix `0b` is recognized.
+    ///
+    /// This setting has no effect if a custom table of prefixes has been set
+    /// with [`ParseOptions::prefixes()`].
+    ///
+    /// Default: `true`
+    #[must_use]
+    pub const fn binary_prefix(mut self, yes: bool) -> ParseOptions {
+        self.binary_prefix = yes;
+        self
+    }
+
+    /// Set the table of base prefixes to recognize in place of `0x`, `0o`, and
+    /// `0b`.
+    ///
+    /// When multiple prefixes in the table match the start of the input (such
+    /// as `0` and `0x`), the longest one is used; among matching prefixes of
+    /// the same length, the first one in the table is used.  Prefixes may be
+    /// of any length, and they may even consist of characters that are valid
+    /// digits, in which case a number in the default radix can only start
+    /// with those characters if no other digits follow them; e.g., with `0`
+    /// as an octal prefix, `"017"` is 15 and `"0"` is zero.  To accept the default prefixes in addition to
+    /// others, include the values of [`BasePrefix::DEFAULTS`] in the table.
+    ///
+    /// Default: the prefixes `0x`, `0o`, and `0b`, as enabled by
+    /// [`ParseOptions::hex_prefix()`], [`ParseOptions::octal_prefix()`], and
+    /// [`ParseOptions::binary_prefix()`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, BasePrefix, ParseOptions};
+    ///
+    /// static ASM: [BasePrefix; 3] = [
+    ///     BasePrefix::new("$", 16),
+    ///     BasePrefix::new("%", 2),
+    ///     BasePrefix::new("0x", 16).ignore_case(true),
+    /// ];
+    ///
+    /// let opts = ParseOptions::new().prefixes(&ASM);
+    /// assert_eq!(strtoint_with::<u8>("$FF", &opts).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<u8>("%1010", &opts).unwrap(), 10);
+    /// assert_eq!(strtoint_with::<u8>("0XfF", &opts).unwrap(), 255);
+    /// assert!(strtoint_with::<u8>("0b1010", &opts).is_err());
+    /// ```
+    #[must_use]
+    pub const fn prefixes(mut self, table: &'static [BasePrefix]) -> ParseOptions {
+        self.prefixes = Some(table);
+        self
+    }
+
+    /// Set the radix of numbers that do not start with a base prefix.
+    ///
+    /// Digits greater than 9 are represented by the ASCII letters `a` through
+    /// `z` (case insensitive).  Note that, in radixes above 11, the `b` of
+    /// the `0b` prefix is also a digit, as is the `o` of `0o` in radixes
+    /// above 24 and the `x` of `0x` in radixes above 33; whether these are
+    /// treated as prefixes or as digits is controlled by
+    /// [`ParseOptions::radix_prefix()`] and
+    /// [`ParseOptions::prefix_conflict()`].
+    ///
+    /// Default: 10
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    #[must_use]
+    pub const fn default_radix(mut self, radix: u32) -> ParseOptions {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the range 2..=36"
+        );
+        self.default_radix = radix;
+        self
+    }
+
+    /// Set whether a number without a base prefix that starts with `0` is
+    /// parsed in radix 8 instead of the [default radix], as in C, so that
+    /// `"017"` is 15.
+    ///
+    /// Default: `false`
+    ///
+    /// [default radix]: ParseOptions::default_radix()
+    #[must_use]
+    pub const fn leading_zero_octal(mut self, yes: bool) -> ParseOptions {
+        self.leading_zero_octal = yes;
+        self
+    }
+
+    /// Set whether numbers that start with `0` follow the rules of
+    /// non-strict JavaScript when [`ParseOptions::leading_zero_octal()`] is
+    /// `true`: such a number may not contain underscores, and it is parsed
+    /// in radix 10 instead of radix 8 if an `8` or `9` appears among its
+    /// digits, so that `"0777"` is 511, `"089"` is 89, and `"0_7"` is
+    /// rejected.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub const fn javascript_octal(mut self, yes: bool) -> ParseOptions {
+        self.javascript_octal = yes;
+        self
+    }
+
+    /// Set whether a base prefix is allowed, required, or forbidden.
+    ///
+    /// Only the prefixes enabled by [`ParseOptions::hex_prefix()`],
+    /// [`ParseOptions::octal_prefix()`], and
+    /// [`ParseOptions::binary_prefix()`] (or those set with
+    /// [`ParseOptions::prefixes()`]) are recognized, and a prefix for a
+    /// radix other than [`ParseOptions::default_radix()`] is handled as set by
+    /// [`ParseOptions::prefix_conflict()`].  When a prefix is required but
+    /// missing, the first character after the sign is reported as an invalid
+    /// character with a reason of [`InvalidReason::MissingPrefix`].
+    ///
+    /// Default: [`PrefixRule::Optional`]
+    ///
+    /// [`InvalidReason::MissingPrefix`]: crate::InvalidReason::MissingPrefix
+    #[must_use]
+    pub const fn radix_prefix(mut self, rule: PrefixRule) -> ParseOptions {
+        self.radix_prefix = rule;
+        self
+    }
+
+    /// Set what happens when the input starts with a base prefix for a radix
+    /// other than [`ParseOptions::default_radix()`], such as `0b` when the
+    /// default radix is 16.
+    ///
+    /// This setting has no effect when [`ParseOptions::radix_prefix()`] is
+    /// [`PrefixRule::Forbidden`].
+    ///
+    /// Default: [`PrefixConflict::Override`]
+    #[must_use]
+    pub const fn prefix_conflict(mut self, policy: PrefixConflict) -> ParseOptions {
+        self.prefix_conflict = policy;
+        self
+    }
+
+    /// Set whether the radix of a number may be given by a trailing letter,
+    /// as in Intel/MASM assembly language.
+    ///
+    /// When this is `true`, a number may end in one of the following suffixes
+    /// (case insensitive), which is not itself treated as a digit:
+    ///
+    /// - `h` — hexadecimal
+    /// - `d` or `t` — decimal
+    /// - `o` or `q` — octal
+    /// - `b` or `y` — binary
+    ///
+    /// As in MASM, a letter that is a valid digit in the default radix is a
+    /// digit rather than a suffix; for example, when the
+    /// [default radix](ParseOptions::default_radix()) is 16, `12d` is the
+    /// hexadecimal number 0x12D, and `y` and `t` must be used for binary and
+    /// decimal instead.  A suffix takes precedence over any base prefix, so
+    /// that `0b1h` is 0xB1.  Numbers without a suffix are parsed as usual.
+    ///
+    /// In addition, a number without a base prefix must start with a decimal
+    /// digit, so that it cannot be mistaken for an identifier: `0FFh` is
+    /// valid, but `FFh` is an error with a reason of
+    /// [`InvalidReason::NoLeadingDigit`].
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().radix_suffixes(true);
+    /// assert_eq!(strtoint_with::<u32>("0FFh", &opts).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<u32>("1bh", &opts).unwrap(), 27);
+    /// assert_eq!(strtoint_with::<u32>("1010b", &opts).unwrap(), 10);
+    /// assert_eq!(strtoint_with::<u32>("17o", &opts).unwrap(), 15);
+    /// assert_eq!(strtoint_with::<u32>("17q", &opts).unwrap(), 15);
+    /// assert_eq!(strtoint_with::<u32>("12d", &opts).unwrap(), 12);
+    /// assert_eq!(strtoint_with::<u32>("12", &opts).unwrap(), 12);
+    /// assert!(strtoint_with::<u32>("FFh", &opts).is_err());
+    ///
+    /// let hex = opts.default_radix(16);
+    /// assert_eq!(strtoint_with::<u32>("12d", &hex).unwrap(), 0x12D);
+    /// assert_eq!(strtoint_with::<u32>("12t", &hex).unwrap(), 12);
+    /// ```
+    ///
+    /// [`InvalidReason::NoLeadingDigit`]: crate::InvalidReason::NoLeadingDigit
+    #[must_use]
+    pub const fn radix_suffixes(mut self, yes: bool) -> ParseOptions {
+        self.radix_suffixes = yes;
+        self
+    }
+
+    /// Set which notation, if any, is recognized for giving the radix of a
+    /// number explicitly as a decimal number followed by `#`, as in
+    /// `16#FF`.  See [`HashRadix`] for the supported notations.
+    ///
+    /// The radix may be any number from 2 through 36, and each digit must be
+    /// valid in that radix.  A radix outside that range results in a
+    /// [`StrToIntError::InvalidRadix`] error pointing at the radix.  A number
+    /// with an explicit radix does not have a base prefix or
+    /// [radix suffix](ParseOptions::radix_suffixes()), and its radix is used
+    /// regardless of [`ParseOptions::default_radix()`] and
+    /// [`ParseOptions::prefix_conflict()`].  The digits after the radix may
+    /// not start with an underscore, as in Erlang.  Numbers without an
+    /// explicit radix are parsed as usual.
+    ///
+    /// Default: [`HashRadix::Disabled`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, HashRadix, ParseOptions, StrToIntError};
+    ///
+    /// let erlang = ParseOptions::new().hash_radix(HashRadix::Erlang);
+    /// assert_eq!(strtoint_with::<u32>("16#FF", &erlang).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<i32>("-36#zz", &erlang).unwrap(), -1295);
+    /// assert_eq!(strtoint_with::<u32>("42", &erlang).unwrap(), 42);
+    /// assert_eq!(
+    ///     strtoint_with::<u32>("37#10", &erlang),
+    ///     Err(StrToIntError::InvalidRadix {
+    ///         radix: 37,
+    ///         position: 0
+    ///     })
+    /// );
+    ///
+    /// let ada = ParseOptions::new().hash_radix(HashRadix::Ada);
+    /// assert_eq!(strtoint_with::<u32>("16#FF_FF#", &ada).unwrap(), 0xFFFF);
+    /// assert!(strtoint_with::<u32>("16#FF", &ada).is_err());
+    ///
+    /// let lisp = ParseOptions::new().hash_radix(HashRadix::Lisp);
+    /// assert_eq!(strtoint_with::<u32>("#xFF", &lisp).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<u32>("#b101", &lisp).unwrap(), 5);
+    /// assert_eq!(strtoint_with::<u32>("#36rZZ", &lisp).unwrap(), 1295);
+    /// assert_eq!(strtoint_with::<i32>("#x-FF", &lisp).unwrap(), -255);
+    /// ```
+    ///
+    /// [`StrToIntError::InvalidRadix`]: crate::StrToIntError::InvalidRadix
+    #[must_use]
+    pub const fn hash_radix(mut self, notation: HashRadix) -> ParseOptions {
+        self.hash_radix = notation;
+        self
+    }
+
+    /// Set whether Verilog based literals are recognized.
+    ///
+    /// A based literal consists of an optional decimal width, an apostrophe,
+    /// an optional `s` marking the literal as signed, a base letter (`b`,
+    /// `o`, `d`, or `h`), and digits, as in `8'hFF`, `4'sd3`, or `'b1010`.
+    /// The width and base are matched case-insensitively, and any sign comes
+    /// before the width, as in `-8'd5`.  As in Verilog, the digits may not
+    /// start with an underscore, and numbers without an apostrophe are
+    /// parsed as usual.
+    ///
+    /// The value of a sized literal must fit in its declared width, or else
+    /// a [`StrToIntError::ExceedsWidth`] error is returned.  A width of zero
+    /// is reported as an invalid character with a reason of
+    /// [`InvalidReason::ZeroWidth`], and a width too large for a `u32` with
+    /// a reason of [`InvalidReason::WidthTooLarge`].  A sized signed literal whose most
+    /// significant bit is set is negative, so `4'sb1111` is -1.  The digits
+    /// `x`, `z`, and `?`, which denote unknown and high-impedance bits, are
+    /// reported with a [`StrToIntError::UnknownDigit`] error.
+    ///
+    /// Use [`strtoint_verilog()`] to also get the declared width of a
+    /// literal.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().verilog_literals(true);
+    /// assert_eq!(strtoint_with::<u32>("8'hFF", &opts).unwrap(), 255);
+    /// assert_eq!(strtoint_with::<u32>("16'b1010_0101", &opts).unwrap(), 0xA5);
+    /// assert_eq!(strtoint_with::<i32>("-4'sd3", &opts).unwrap(), -3);
+    /// assert_eq!(strtoint_with::<i32>("4'sb1111", &opts).unwrap(), -1);
+    /// assert_eq!(strtoint_with::<u32>("'o17", &opts).unwrap(), 15);
+    /// assert!(strtoint_with::<u32>("4'd16", &opts).is_err());
+    /// assert!(strtoint_with::<u32>("8'hxF", &opts).is_err());
+    /// ```
+    ///
+    /// [`StrToIntError::ExceedsWidth`]: crate::StrToIntError::ExceedsWidth
+    /// [`StrToIntError::UnknownDigit`]: crate::StrToIntError::UnknownDigit
+    /// [`InvalidReason::ZeroWidth`]: crate::InvalidReason::ZeroWidth
+    /// [`InvalidReason::WidthTooLarge`]: crate::InvalidReason::WidthTooLarge
+    /// [`strtoint_verilog()`]: crate::strtoint_verilog()
+    #[must_use]
+    pub const fn verilog_literals(mut self, yes: bool) -> ParseOptions {
+        self.verilog_literals = yes;
+        self
+    }
+
+    /// Set where underscores are allowed to appear among the digits.
+    ///
+    /// Default: [`Underscores::Rust`]
+    #[must_use]
+    pub const fn underscores(mut self, rule: Underscores) -> ParseOptions {
+        self.underscores = rule;
+        self
+    }
+
+    /// Set whether a number without a base prefix may start with a zero
+    /// that is followed by other digits.
+    ///
+    /// Numbers parsed as octal because of
+    /// [`ParseOptions::leading_zero_octal()`] are not affected.
+    ///
+    /// Default: [`LeadingZeros::Allowed`]
+    #[must_use]
+    pub const fn leading_zeros(mut self, rule: LeadingZeros) -> ParseOptions {
+        self.leading_zeros = rule;
+        self
+    }
+
+    /// Set whether non-ASCII decimal digits (those in Unicode general
+    /// category Nd, such as the Arabic-Indic digits `٠` through `٩`) are
+    /// accepted as digits with the same values as the ASCII digits `0`
+    /// through `9`, as in Python.  They are accepted wherever their values
+    /// are valid digits in the radix, and a non-ASCII zero followed by a
+    /// prefix letter is recognized as a base prefix.  Non-ASCII digits are
+    /// never recognized in byte input.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().unicode_digits(true);
+    /// assert_eq!(strtoint_with::<u32>("١٢٣", &opts).unwrap(), 123);
+    /// assert_eq!(strtoint_with::<u32>("१_०००", &opts).unwrap(), 1000);
+    /// assert_eq!(strtoint_with::<u32>("0b١٠", &opts).unwrap(), 2);
+    /// ```
+    #[must_use]
+    pub const fn unicode_digits(mut self, yes: bool) -> ParseOptions {
+        self.unicode_digits = yes;
+        self
+    }
+
+    /// Set whether a decimal number without a base prefix may be followed by
+    /// base-60 components separated by colons, as in YAML 1.1, where
+    /// `190:20:30` denotes 190 × 60² + 20 × 60 + 30.
+    ///
+    /// Each component after the first must consist of one or two digits and
+    /// have a value from 0 to 59, and it may not contain underscores.  An
+    /// invalid component is reported as an invalid character with a reason
+    /// of [`InvalidReason::InvalidSexagesimal`].  This setting has no effect
+    /// unless [`ParseOptions::default_radix()`] is 10.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().sexagesimal(true);
+    /// assert_eq!(strtoint_with::<u32>("190:20:30", &opts).unwrap(), 685_230);
+    /// assert_eq!(strtoint_with::<i32>("-1:05", &opts).unwrap(), -65);
+    /// assert!(strtoint_with::<u32>("1:60", &opts).is_err());
+    /// ```
+    ///
+    /// [`InvalidReason::InvalidSexagesimal`]: crate::InvalidReason::InvalidSexagesimal
+    #[must_use]
+    pub const fn sexagesimal(mut self, yes: bool) -> ParseOptions {
+        self.sexagesimal = yes;
+        self
+    }
+
+    /// Set whether the digits may be followed by a Rust integer type suffix
+    /// (`i8`, `u8`, `i16`, …, `isize`, `usize`), as in `255u8` or `0x10_i64`.
+    ///
+    /// As in Rust, the digits of a binary or octal number may not be followed
+    /// by a decimal digit, so `0b12` is an invalid digit rather than the
+    /// suffix `2`, and a suffix must come after at least one digit.
+    ///
+    /// A suffix that names a type other than the one being parsed results in
+    /// a [`StrToIntError::SuffixMismatch`] error; an unrecognized suffix is
+    /// reported as an invalid character at its start with a reason of
+    /// [`InvalidReason::InvalidSuffix`].  A `NonZero` type
+    /// accepts the suffix of its corresponding primitive type.
+    ///
+    /// Default: `false`
+    ///
+    /// [`StrToIntError::SuffixMismatch`]: crate::StrToIntError::SuffixMismatch
+    /// [`InvalidReason::InvalidSuffix`]: crate::InvalidReason::InvalidSuffix
+    #[must_use]
+    pub const fn type_suffixes(mut self, yes: bool) -> ParseOptions {
+        self.type_suffixes = yes;
+        self
+    }
+
+    /// Set whether the digits may be followed by the JavaScript `BigInt`
+    /// suffix `n`, as in `123n` or `0x1Fn`.
+    ///
+    /// As in JavaScript, the suffix must come after at least one digit, and
+    /// it may not follow a number with a leading zero that is read under
+    /// [`ParseOptions::leading_zero_octal()`], such as `0777n` or `089n`; in
+    /// that case, the suffix is reported as an invalid character with a
+    /// reason of [`InvalidReason::DisallowedBigIntSuffix`].  In a radix of 24
+    /// or more, `n` is a digit rather than a suffix.
+    ///
+    /// Default: `false`
+    ///
+    /// [`InvalidReason::DisallowedBigIntSuffix`]: crate::InvalidReason::DisallowedBigIntSuffix
+    #[must_use]
+    pub const fn bigint_suffix(mut self, yes: bool) -> ParseOptions {
+        self.bigint_suffix = yes;
+        self
+    }
+
+    /// Set what happens when the numeric value of the input is out of range
+    /// for the numeric type.
+    ///
+    /// Use [`strtoint_overflowing_with()`](crate::strtoint_overflowing_with())
+    /// to find out whether a value was clamped or wrapped.
+    ///
+    /// Default: [`Overflow::Error`]
+    #[must_use]
+    pub const fn overflow(mut self, mode: Overflow) -> ParseOptions {
+        self.overflow = mode;
+        self
+    }
+}
+
+impl ParseOptions {
+    /// Return the longest recognized base prefix that `s` starts with, along
+    /// with the length of the match in `s`.  If `zero_len` is nonzero, `s`
+    /// starts with a non-ASCII decimal zero of that many bytes, which is
+    /// matched as `0`.
+    ///
+    /// A prefix made up of digits in the default radix that is not followed
+    /// by a decimal digit or a digit in the prefix's radix (ignoring
+    /// underscores) is not matched, so that it is read as digits instead.
+    pub(crate) fn match_prefix(&self, s: &[u8], zero_len: usize) -> Option<(BasePrefix, usize)> {
+        let matched = match self.prefixes {
+            Some(table) => BasePrefix::longest_match(table.iter().copied(), s, zero_len),
+            None => BasePrefix::longest_match(
+                [
+                    (BasePrefix::HEX, self.hex_prefix),
+                    (BasePrefix::OCTAL, self.octal_prefix),
+                    (BasePrefix::BINARY, self.binary_prefix),
+                ]
+                .into_iter()
+                .filter_map(|(p, enabled)| enabled.then_some(p)),
+                s,
+                zero_len,
+            ),
+        };
+        matched.filter(|&(p, len)| {
+            !p.text().chars().all(|c| c.is_digit(self.default_radix))
+                || s[len..]
+                    .iter()
+                    .find(|&&b| b != b'_')
+                    .is_some_and(|&b| b.is_ascii_digit() || char::from(b).is_digit(p.radix()))
+        })
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+/// Rules for whether an integer string may start with a base prefix
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PrefixRule {
+    /// A base prefix may be present or absent
+    #[default]
+    Optional,
+
+    /// A base prefix must be present
+    Required,
+
+    /// Base prefixes are not recognized; a base prefix at the start of the
+    /// input is treated as digits (if valid in the default radix) or as
+    /// invalid characters
+    Forbidden,
+}
+
+/// Behaviors for when an integer string starts with a base prefix for a
+/// radix other than [`ParseOptions::default_radix()`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PrefixConflict {
+    /// The prefix takes precedence, and the number is parsed in the radix of
+    /// the prefix
+    #[default]
+    Override,
+
+    /// The prefix letter is reported as an invalid character with a reason
+    /// of [`InvalidReason::ConflictingPrefix`]
+    ///
+    /// [`InvalidReason::ConflictingPrefix`]: crate::InvalidReason::ConflictingPrefix
+    Error,
+
+    /// The prefix is not recognized and is treated as digits (if valid in
+    /// the default radix) or as invalid characters, so that, for example,
+    /// `0b1` is parsed as 0xB1 when the default radix is 16
+    Ignore,
+}
+
+/// Notations for giving the radix of a number explicitly, for use with
+/// [`ParseOptions::hash_radix()`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HashRadix {
+    /// No explicit radix notation is recognized
+    #[default]
+    Disabled,
+
+    /// The Erlang and Bash notation `radix#digits`, e.g., `16#FF` or
+    /// `36#zz`.  The radix comes after any sign, as in `-16#FF`.
+    Erlang,
+
+    /// The Ada notation `radix#digits#`, e.g., `16#FF_FF#`.  The radix comes
+    /// after any sign, and a missing closing `#` is reported at the opening
+    /// `#` with a reason of [`InvalidReason::UnclosedHash`], unless the
+    /// digits contain an invalid character, which is reported instead.
+    ///
+    /// [`InvalidReason::UnclosedHash`]: crate::InvalidReason::UnclosedHash
+    Ada,
+
+    /// The Common Lisp notations `#xdigits`, `#odigits`, `#bdigits`, and
+    /// `#radixrdigits`, matched case-insensitively, e.g., `#xFF` or
+    /// `#36rZZ`.  The radix comes before any sign, as in `#x-FF`.
+    Lisp,
+}
+
+/// Rules for where underscores may appear in an integer string
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Underscores {
+    /// Underscores are not allowed at all
+    Forbidden,
+
+    /// Underscores may appear anywhere after the sign and base prefix, except
+    /// that a number without a base prefix may not start with an underscore.
+    /// This matches Rust's rules for integer literals.
+    #[default]
+    Rust,
+
+    /// Underscores may only appear singly between two digits; leading,
+    /// trailing, and consecutive underscores are not allowed, nor is an
+    /// underscore immediately after a base prefix.
+    BetweenDigits,
+
+    /// Underscores may only appear singly between two digits or between a
+    /// base prefix and the first digit; leading, trailing, and consecutive
+    /// underscores are not allowed.  This matches Python's rules for integer
+    /// literals, under which `0x_1F` is valid but `_1F`, `0x__1F`, and
+    /// `1F_` are not.
+    Python,
+}
+
+/// Rules for whether a number without a base prefix may start with a zero
+/// that is followed by other digits
+///
+/// A disallowed leading zero is reported as an invalid character with a
+/// reason of [`InvalidReason::LeadingZero`].
+///
+/// [`InvalidReason::LeadingZero`]: crate::InvalidReason::LeadingZero
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LeadingZeros {
+    /// Leading zeros are allowed, so `0644` is parsed as 644
+    #[default]
+    Allowed,
+
+    /// Leading zeros are only allowed if all of the digits are zero, so
+    /// `00` is valid but `0644` is not.  This matches Python's rules for
+    /// integer literals.
+    AllZeros,
+
+    /// A zero may not be followed by any other digits, so neither `00` nor
+    /// `0644` is valid.  This matches the rules of TOML and JSON.
+    Forbidden,
+}
+
+/// Behaviors for when the numeric value of an input string is out of range
+/// for the numeric type
+///
+/// For the `NonZero` types, the range is that of the corresponding primitive
+/// type.  If the resulting value would be zero, a
+/// [`Zero`](crate::StrToIntError::Zero) error is returned — unless
+/// the zero came from saturating a negative value for an unsigned type (with
+/// [`ParseOptions::unsigned_minus()`] enabled), in which case the value is
+/// clamped to one instead.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Overflow {
+    /// Return an [`OutOfRange`](crate::StrToIntError::OutOfRange) error
+    #[default]
+    Error,
+
+    /// Clamp the value to the type's `MIN` or `MAX`
+    Saturate,
+
+    /// Wrap the value modulo 2<sup>N</sup>, where N is the number of bits in
+    /// the type.  This is equivalent to keeping only the low N bits of the
+    /// value's two's-complement representation, as an `as` cast from a wider
+    /// type would.
+    Wrap,
+}
@@ -0,0 +1,1041 @@
+use crate::unicode::{decimal_value, is_unicode_space};
+use crate::{
+    ErrorSink, HashRadix, IntSuffix, InvalidReason, LeadingZeros, Overflow, ParseOptions,
+    PrefixConflict, PrefixRule, StrToIntError, Underscores, VerilogSpec,
+};
+
+/// Information about a primitive integer type needed by [`parse()`]
+pub(crate) trait Prim: Sized {
+    /// Whether the type is signed
+    const SIGNED: bool;
+
+    /// The magnitude of the type's maximum value
+    const MAX_MAGNITUDE: u128;
+
+    /// The magnitude of the type's minimum value
+    const MIN_MAGNITUDE: u128;
+
+    /// The type's literal suffix
+    const SUFFIX: IntSuffix;
+
+    /// Construct a value from a sign and a magnitude that is known to be in
+    /// range
+    fn from_magnitude(negative: bool, magnitude: u128) -> Self;
+}
+
+/// The input to [`parse()`]: either a string or a byte slice
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Input<'a> {
+    Str(&'a str),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> Input<'a> {
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Input::Str(s) => s.as_bytes(),
+            Input::Bytes(bs) => bs,
+        }
+    }
+
+    /// Return the character to report as invalid when the (non-digit) byte at
+    /// `position` is rejected.  For string input, this is the full character
+    /// starting at that position; for byte input, this is the byte itself
+    /// converted to a `char`.
+    fn char_at(&self, position: usize) -> char {
+        match self {
+            Input::Str(s) => s[position..].chars().next().unwrap_or_default(),
+            Input::Bytes(bs) => bs
+                .get(position)
+                .copied()
+                .map(char::from)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// If the character at `position` is a non-ASCII decimal digit, return
+    /// its value and its length in bytes.  For byte input, this is always
+    /// `None`.
+    fn unicode_digit_at(&self, position: usize) -> Option<(u32, usize)> {
+        match self {
+            Input::Str(s) => {
+                let c = s
+                    .get(position..)?
+                    .chars()
+                    .next()
+                    .filter(|c| !c.is_ascii())?;
+                Some((decimal_value(c)?, c.len_utf8()))
+            }
+            Input::Bytes(_) => None,
+        }
+    }
+
+    /// Return the length in bytes of the whitespace at the start of the
+    /// input.  If `unicode` is true, the characters of [`is_unicode_space()`]
+    /// are included; for byte input, they never are.
+    fn leading_space_len(&self, unicode: bool) -> usize {
+        match self {
+            Input::Str(s) => s.len() - s.trim_start_matches(|c| is_space(c, unicode)).len(),
+            Input::Bytes(bs) => bs.iter().take_while(|&&b| is_c_space(b)).count(),
+        }
+    }
+
+    /// Return the length in bytes of the whitespace at the end of the input
+    /// after `start`, which must be a character boundary.  `unicode` is as
+    /// for [`Input::leading_space_len()`].
+    fn trailing_space_len(&self, start: usize, unicode: bool) -> usize {
+        match self {
+            Input::Str(s) => {
+                let s = &s[start..];
+                s.len() - s.trim_end_matches(|c| is_space(c, unicode)).len()
+            }
+            Input::Bytes(bs) => bs[start..]
+                .iter()
+                .rev()
+                .take_while(|&&b| is_c_space(b))
+                .count(),
+        }
+    }
+
+    /// Test whether `position` is the start of a character.  For byte input,
+    /// this is always true.
+    fn is_char_boundary(&self, position: usize) -> bool {
+        match self {
+            Input::Str(s) => s.is_char_boundary(position),
+            Input::Bytes(_) => true,
+        }
+    }
+}
+
+/// The result of a successful call to [`parse()`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Parsed<T> {
+    /// The parsed value
+    pub(crate) value: T,
+
+    /// The number of bytes of input consumed
+    pub(crate) len: usize,
+
+    /// Whether the numeric value of the input was out of range for `T` and
+    /// was clamped or wrapped in accordance with [`ParseOptions::overflow`]
+    pub(crate) overflowed: bool,
+}
+
+/// The errors encountered by [`scan()`]
+struct Errors<'a> {
+    /// Where to push errors when collecting all of them
+    sink: Option<&'a mut dyn ErrorSink>,
+
+    /// The first error encountered
+    first: Option<StrToIntError>,
+}
+
+impl Errors<'_> {
+    /// Record an error.  If errors are not being collected, the error is
+    /// returned so that the caller can stop parsing immediately.
+    fn report(&mut self, e: StrToIntError) -> Result<(), StrToIntError> {
+        let Some(sink) = self.sink.as_deref_mut() else {
+            return Err(e);
+        };
+        sink.push(e);
+        self.first.get_or_insert(e);
+        Ok(())
+    }
+}
+
+/// Parse `input` as an integer of type `T` according to `options`.
+///
+/// If `partial` is false, the entire input must be a valid integer.  If
+/// `partial` is true, parsing stops at the first byte that cannot continue
+/// the integer, and only the bytes before it are consumed; an error is only
+/// returned if no digits could be consumed at all or if the value is out of
+/// range.
+pub(crate) fn parse<T: Prim>(
+    input: Input<'_>,
+    options: &ParseOptions,
+    partial: bool,
+) -> Result<Parsed<T>, StrToIntError> {
+    let errors = Errors {
+        sink: None,
+        first: None,
+    };
+    scan(input, options, partial, errors)
+}
+
+/// Parse all of `input` as an integer of type `T` according to `options`,
+/// pushing every error encountered to `sink` instead of stopping at the first
+/// one.
+///
+/// The return value is the same as that of `parse(input, options, false)`.
+pub(crate) fn parse_all_errors<T: Prim>(
+    input: Input<'_>,
+    options: &ParseOptions,
+    sink: &mut dyn ErrorSink,
+) -> Result<Parsed<T>, StrToIntError> {
+    let errors = Errors {
+        sink: Some(sink),
+        first: None,
+    };
+    scan(input, options, false, errors)
+}
+
+/// The implementation of [`parse()`] and [`parse_all_errors()`].
+///
+/// Whenever an error is encountered, it is reported to `errors`.  If errors
+/// are being collected, scanning then resumes in the manner that will find
+/// the most other problems: an invalid character is skipped, a `-` sign for an
+/// unsigned type is ignored, and no further out-of-range errors are reported
+/// once the value has gone out of range.
+///
+/// Scanning happens in three phases: [`scan_head()`] finds the sign and the
+/// radix, [`scan_digits()`] accumulates the digits, and [`scan_tail()`]
+/// checks whatever follows them.
+fn scan<T: Prim>(
+    input: Input<'_>,
+    options: &ParseOptions,
+    partial: bool,
+    mut errors: Errors<'_>,
+) -> Result<Parsed<T>, StrToIntError> {
+    let head = scan_head::<T>(input, options, partial, &mut errors)?;
+    let mut digits = scan_digits::<T>(input, &head, options, partial, &mut errors)?;
+    if let Some((width, true)) = head.sized.filter(|_| !digits.overflowed) {
+        digits.reinterpret_signed::<T>(width, head.offset, options, &mut errors)?;
+    }
+    if !digits.digit_seen {
+        if let Some(len) = head.bare_zero_len {
+            return Ok(Parsed {
+                value: T::from_magnitude(head.negative, 0),
+                len,
+                overflowed: false,
+            });
+        }
+        errors.report(StrToIntError::NoDigits)?;
+    }
+    let end = scan_tail::<T>(input, &head, &digits, options, partial, &mut errors)?;
+    if let Some(e) = errors.first {
+        return Err(e);
+    }
+    let mut negative = digits.negative;
+    if head.negated_unsigned::<T>(options)
+        && digits.overflowed
+        && options.overflow == Overflow::Saturate
+    {
+        // `strtoul()` returns the maximum value on overflow regardless of
+        // sign.
+        negative = false;
+    }
+    Ok(Parsed {
+        value: T::from_magnitude(negative, digits.magnitude),
+        len: end,
+        overflowed: digits.overflowed,
+    })
+}
+
+/// What [`scan_head()`] found before the digits of a number
+struct Head<'a> {
+    /// The digits and whatever follows them, without any trailing whitespace,
+    /// radix suffix, or closing `#`
+    digits: &'a [u8],
+
+    /// The position of the first digit
+    offset: usize,
+
+    /// The length of the input without any trailing whitespace to skip
+    trimmed_len: usize,
+
+    /// The end of the integer, if it is followed by a radix suffix or closing
+    /// `#` that has been removed from `digits`
+    token_end: Option<usize>,
+
+    /// The position of the sign, or of what follows it if there is no sign
+    sign_start: usize,
+
+    /// Whether a base prefix or radix specification came between the sign
+    /// and the digits
+    prefixed: bool,
+
+    /// Whether the radix was given by a base prefix
+    base_prefix: bool,
+
+    /// Whether the number is negative
+    negative: bool,
+
+    /// The radix of the digits
+    radix: u32,
+
+    /// Whether the number has no base prefix or other indication of its radix
+    unprefixed: bool,
+
+    /// Whether the number has a leading zero followed by another digit that
+    /// made it octal (or decimal, under
+    /// [`ParseOptions::javascript_octal()`])
+    legacy_octal: bool,
+
+    /// Where underscores may appear among the digits
+    underscores: Underscores,
+
+    /// The declared width of a Verilog literal and whether it is signed
+    sized: Option<(u32, bool)>,
+
+    /// Whether the number is a Verilog literal
+    verilog: bool,
+
+    /// When parsing partially and the number starts with a base prefix that
+    /// starts with "0", the length of the input through that "0", which is
+    /// treated as a lone "0" followed by unparsed text if no digits follow
+    /// the prefix
+    bare_zero_len: Option<usize>,
+}
+
+impl Head<'_> {
+    /// Whether the magnitude is to be negated modulo 2^N after checking it
+    /// against the type's maximum, as by C's `strtoul()`
+    fn negated_unsigned<T: Prim>(&self, options: &ParseOptions) -> bool {
+        self.negative && !T::SIGNED && options.negate_unsigned
+    }
+
+    /// Test whether an underscore may appear at the current point in the
+    /// digits, given whether any digits have been seen and whether the
+    /// underscore immediately follows another underscore
+    fn underscore_allowed(&self, digit_seen: bool, after_underscore: bool) -> bool {
+        match self.underscores {
+            Underscores::Forbidden => false,
+            Underscores::Rust => digit_seen || self.base_prefix,
+            Underscores::BetweenDigits => digit_seen && !after_underscore,
+            Underscores::Python => (digit_seen || self.base_prefix) && !after_underscore,
+        }
+    }
+}
+
+/// Scan the part of `input` before the digits: leading whitespace, the sign,
+/// and whatever indicates the radix.  Trailing whitespace and any radix
+/// suffix or closing `#` are split off from the digits as well.
+fn scan_head<'a, T: Prim>(
+    input: Input<'a>,
+    options: &ParseOptions,
+    partial: bool,
+    errors: &mut Errors<'_>,
+) -> Result<Head<'a>, StrToIntError> {
+    let mut s = input.as_bytes();
+    let mut offset = 0;
+    let unicode_space = options.unicode_whitespace;
+    if options.leading_whitespace {
+        offset = input.leading_space_len(unicode_space);
+        s = &s[offset..];
+    }
+    // The length of the input without any trailing whitespace to skip
+    let mut trimmed_len = input.as_bytes().len();
+    if options.trailing_whitespace && !partial {
+        trimmed_len -= input.trailing_space_len(offset, unicode_space);
+        s = &s[..trimmed_len - offset];
+    }
+    // Radix specified with `#` notation
+    let mut spec_radix = None;
+    // In Common Lisp notation, the radix specification precedes the sign.
+    if let Some(spec) = lisp_spec(s).filter(|_| options.hash_radix == HashRadix::Lisp) {
+        spec_radix = Some(spec.check(offset, errors)?);
+        offset += spec.len;
+        s = &s[spec.len..];
+    }
+    let sign_start = offset;
+    let negative = {
+        if let Some(t) = s.strip_prefix(b"+").filter(|_| options.plus_sign) {
+            offset += 1;
+            s = t;
+            false
+        } else if let Some(t) = s.strip_prefix(b"-") {
+            let position = offset;
+            offset += 1;
+            s = t;
+            if !T::SIGNED && !options.unsigned_minus && !options.negate_unsigned {
+                errors.report(StrToIntError::InvalidCharacter {
+                    c: '-',
+                    position,
+                    reason: InvalidReason::UnsignedMinus,
+                })?;
+                false
+            } else {
+                true
+            }
+        } else {
+            false
+        }
+    };
+    let prefix_start = offset;
+    // The end of the integer, if it is followed by a radix suffix or closing
+    // `#` that has been removed from `s`
+    let mut token_end = None;
+    if let Some(spec) = infix_spec(s).filter(|_| {
+        spec_radix.is_none() && matches!(options.hash_radix, HashRadix::Erlang | HashRadix::Ada)
+    }) {
+        let radix = spec.check(offset, errors)?;
+        spec_radix = Some(radix);
+        let hash = offset + spec.len - 1;
+        offset += spec.len;
+        s = &s[spec.len..];
+        if options.hash_radix == HashRadix::Ada {
+            match split_closing_hash(s, radix, partial) {
+                Ok(len) => {
+                    token_end = Some(offset + len + 1);
+                    s = &s[..len];
+                }
+                Err(true) => errors.report(StrToIntError::InvalidCharacter {
+                    c: '#',
+                    position: hash,
+                    reason: InvalidReason::UnclosedHash,
+                })?,
+                Err(false) => (),
+            }
+        }
+    }
+    // The declared width of a Verilog literal and whether it is signed
+    let mut sized = None;
+    let verilog =
+        VerilogSpec::parse(s).filter(|_| spec_radix.is_none() && options.verilog_literals);
+    if let Some(spec) = verilog {
+        if let Some(reason) = spec.width_error() {
+            errors.report(StrToIntError::InvalidCharacter {
+                c: input.char_at(offset),
+                position: offset,
+                reason,
+            })?;
+        }
+        spec_radix = Some(spec.radix);
+        sized = spec.width.filter(|&w| w > 0).map(|w| (w, spec.signed));
+        offset += spec.len;
+        s = &s[spec.len..];
+    }
+    let suffix = radix_suffix(s, partial, options)
+        .filter(|_| spec_radix.is_none())
+        .map(|(radix, len)| {
+            token_end = Some(offset + len + 1);
+            s = &s[..len];
+            radix
+        });
+    // The length of a non-ASCII decimal zero at the start of `s`, which is
+    // treated like `0` when matching base prefixes
+    let zero_len = input
+        .unicode_digit_at(offset)
+        .filter(|&(d, _)| d == 0 && options.unicode_digits)
+        .map_or(0, |(_, len)| len);
+    let prefix = options.match_prefix(s, zero_len).filter(|&(p, _)| {
+        spec_radix.is_none()
+            && suffix.is_none()
+            && options.radix_prefix != PrefixRule::Forbidden
+            && (p.radix() == options.default_radix
+                || options.prefix_conflict != PrefixConflict::Ignore)
+    });
+    // Whether the number has no base prefix or other indication of its radix
+    let mut unprefixed = false;
+    // Whether the number is octal because of a leading zero followed by
+    // another digit
+    let mut legacy_octal = false;
+    let mut underscores = options.underscores;
+    let radix = if let Some(r) = spec_radix.or(suffix) {
+        r
+    } else if let Some((p, len)) = prefix {
+        if !options.signed_prefixes && prefix_start > sign_start {
+            errors.report(StrToIntError::InvalidCharacter {
+                c: input.char_at(sign_start),
+                position: sign_start,
+                reason: InvalidReason::DisallowedSign,
+            })?;
+        }
+        if p.radix() != options.default_radix && options.prefix_conflict == PrefixConflict::Error {
+            let position = offset + len - p.len() + p.last_char_offset();
+            errors.report(StrToIntError::InvalidCharacter {
+                c: input.char_at(position),
+                position,
+                reason: InvalidReason::ConflictingPrefix,
+            })?;
+        }
+        offset += len;
+        s = &s[len..];
+        p.radix()
+    } else if options.leading_zero_octal && s.first() == Some(&b'0') {
+        legacy_octal = s.get(1).is_some_and(u8::is_ascii_digit);
+        if options.javascript_octal {
+            underscores = Underscores::Forbidden;
+        }
+        leading_zero_radix(s, options)
+    } else {
+        if options.radix_prefix == PrefixRule::Required && !s.is_empty() {
+            errors.report(StrToIntError::InvalidCharacter {
+                c: input.char_at(offset),
+                position: offset,
+                reason: InvalidReason::MissingPrefix,
+            })?;
+        }
+        unprefixed = true;
+        options.default_radix
+    };
+    if options.radix_suffixes && spec_radix.is_none() && prefix.is_none() {
+        if let Some(&b) = s.first().filter(|b| b.is_ascii_alphabetic()) {
+            if char::from(b).is_digit(radix) {
+                errors.report(StrToIntError::InvalidCharacter {
+                    c: char::from(b),
+                    position: offset,
+                    reason: InvalidReason::NoLeadingDigit,
+                })?;
+            }
+        }
+    }
+    // When parsing partially, a base prefix that starts with "0" and is not
+    // followed by any digits is treated as a lone "0" followed by unparsed
+    // text.
+    let bare_zero_len = (partial && prefix.is_some_and(|(p, _)| p.text().starts_with('0')))
+        .then_some(prefix_start + zero_len.max(1));
+    Ok(Head {
+        digits: s,
+        offset,
+        trimmed_len,
+        token_end,
+        sign_start,
+        prefixed: offset > prefix_start,
+        base_prefix: prefix.is_some(),
+        negative,
+        radix,
+        unprefixed,
+        legacy_octal,
+        underscores,
+        sized,
+        verilog: verilog.is_some(),
+        bare_zero_len,
+    })
+}
+
+/// What [`scan_digits()`] found
+struct Digits {
+    /// The magnitude of the value, clamped or wrapped if it overflowed
+    magnitude: u128,
+
+    /// Whether the value is negative
+    negative: bool,
+
+    /// Whether the value went out of range
+    overflowed: bool,
+
+    /// Whether any digits were seen
+    digit_seen: bool,
+
+    /// The position of the last underscore if it came after the last digit
+    pending_underscore: Option<usize>,
+
+    /// The position at which the digits end
+    end: usize,
+}
+
+impl Digits {
+    /// Reinterpret the magnitude of a signed Verilog literal with the given
+    /// width as two's complement and check the result against the range of
+    /// `T`, reporting any error at `position`
+    fn reinterpret_signed<T: Prim>(
+        &mut self,
+        width: u32,
+        position: usize,
+        options: &ParseOptions,
+        errors: &mut Errors<'_>,
+    ) -> Result<(), StrToIntError> {
+        if self
+            .magnitude
+            .checked_shr(width - 1)
+            .is_some_and(|m| m != 0)
+        {
+            self.magnitude = self.magnitude.wrapping_neg() & width_limit(width);
+            self.negative = !self.negative;
+        }
+        let limit = if self.negative {
+            T::MIN_MAGNITUDE
+        } else {
+            T::MAX_MAGNITUDE
+        };
+        if self.magnitude > limit {
+            match options.overflow {
+                Overflow::Error => errors.report(StrToIntError::out_of_range(
+                    self.negative,
+                    T::MIN_MAGNITUDE,
+                    T::MAX_MAGNITUDE,
+                    position,
+                ))?,
+                Overflow::Saturate => self.magnitude = limit,
+                Overflow::Wrap => (),
+            }
+            self.overflowed = true;
+        }
+        Ok(())
+    }
+}
+
+/// Accumulate the digits of the number whose start was found by
+/// [`scan_head()`], stopping at the first character that cannot continue
+/// them when parsing partially or when a suffix may follow
+fn scan_digits<T: Prim>(
+    input: Input<'_>,
+    head: &Head<'_>,
+    options: &ParseOptions,
+    partial: bool,
+    errors: &mut Errors<'_>,
+) -> Result<Digits, StrToIntError> {
+    let s = head.digits;
+    let offset = head.offset;
+    let radix = head.radix;
+    let negative = head.negative;
+    // The largest value that fits in the declared width of a Verilog literal
+    let width_limit = head.sized.map_or(u128::MAX, |(w, _)| width_limit(w));
+    // A signed Verilog literal is only checked against the range of `T` after
+    // it has been reinterpreted as two's complement.
+    let signed_sized = head.sized.is_some_and(|(_, signed)| signed);
+    let negated_unsigned = head.negated_unsigned::<T>(options);
+    let limit = if signed_sized {
+        width_limit
+    } else if negative && !negated_unsigned {
+        T::MIN_MAGNITUDE
+    } else {
+        T::MAX_MAGNITUDE
+    };
+    let mut magnitude = 0u128;
+    let mut overflowed = false;
+    let mut digit_seen = false;
+    // Position of the most recent underscore if it immediately preceded the
+    // current character
+    let mut pending_underscore = None;
+    // Position of the first digit if it is a zero that may not be followed
+    // by other digits
+    let mut leading_zero = None;
+    // Whether the number may have base-60 components after colons
+    let sexagesimal = options.sexagesimal && head.unprefixed && radix == 10;
+    // The multipliers for the remaining digits of the current base-60
+    // component, if any
+    let mut component: Option<&[u32]> = None;
+    let mut end = head.token_end.unwrap_or(offset + s.len());
+    for (i, &b) in s.iter().enumerate() {
+        let position = i + offset;
+        if !input.is_char_boundary(position) {
+            // This can only happen when skipping past the rest of a non-ASCII
+            // digit or of an invalid non-ASCII character while collecting
+            // errors.
+            continue;
+        }
+        let digit = if b == b':' && sexagesimal && digit_seen {
+            component = Some(match sexagesimal_multipliers(&s[i + 1..]) {
+                Some(multipliers) => multipliers,
+                None if partial => {
+                    end = position;
+                    break;
+                }
+                None => {
+                    let position = if i + 1 < s.len() {
+                        position + 1
+                    } else {
+                        position
+                    };
+                    errors.report(StrToIntError::InvalidCharacter {
+                        c: input.char_at(position),
+                        position,
+                        reason: InvalidReason::InvalidSexagesimal,
+                    })?;
+                    &[]
+                }
+            });
+            continue;
+        } else if b == b'_' {
+            if component.is_none()
+                && head.underscore_allowed(digit_seen, pending_underscore.is_some())
+            {
+                pending_underscore = Some(position);
+                continue;
+            }
+            None
+        } else if b.is_ascii() {
+            char::from(b).to_digit(radix)
+        } else {
+            input
+                .unicode_digit_at(position)
+                .map(|(d, _)| d)
+                .filter(|&d| options.unicode_digits && d < radix)
+        };
+        let Some(digit) = digit else {
+            if head.verilog && matches!(b, b'x' | b'X' | b'z' | b'Z' | b'?') {
+                errors.report(StrToIntError::UnknownDigit {
+                    c: char::from(b),
+                    position,
+                })?;
+                continue;
+            } else if digit_seen
+                && (partial
+                    || (options.type_suffixes && b.is_ascii_alphabetic())
+                    || (options.bigint_suffix && b == b'n'))
+            {
+                end = position;
+                break;
+            } else if head.bare_zero_len.is_some() {
+                break;
+            } else {
+                let reason = match b {
+                    b'_' if digit_seen => InvalidReason::MisplacedUnderscore,
+                    b'_' => InvalidReason::LeadingUnderscore,
+                    _ => InvalidReason::classify(
+                        b,
+                        position,
+                        head.sign_start,
+                        head.prefixed,
+                        digit_seen,
+                    ),
+                };
+                errors.report(StrToIntError::InvalidCharacter {
+                    c: input.char_at(position),
+                    position,
+                    reason,
+                })?;
+                continue;
+            }
+        };
+        if head.unprefixed && options.leading_zeros != LeadingZeros::Allowed {
+            if !digit_seen && digit == 0 {
+                leading_zero = Some(position);
+            } else if let Some(zero) = leading_zero
+                .filter(|_| digit != 0 || options.leading_zeros == LeadingZeros::Forbidden)
+            {
+                errors.report(StrToIntError::InvalidCharacter {
+                    c: input.char_at(zero),
+                    position: zero,
+                    reason: InvalidReason::LeadingZero,
+                })?;
+                leading_zero = None;
+            }
+        }
+        let multiplier = match component {
+            Some([m, rest @ ..]) => {
+                component = Some(rest);
+                *m
+            }
+            // This can only happen after an invalid base-60 component while
+            // collecting errors.
+            Some([]) => continue,
+            None => radix,
+        };
+        let next = magnitude
+            .checked_mul(u128::from(multiplier))
+            .and_then(|m| m.checked_add(u128::from(digit)));
+        if let Some((width, _)) = head.sized {
+            if !overflowed && next.map_or(true, |m| m > width_limit) {
+                errors.report(StrToIntError::ExceedsWidth { width, position })?;
+                overflowed = true;
+            }
+        }
+        let next = next.filter(|&m| m <= limit);
+        magnitude = match (next, options.overflow) {
+            (Some(m), _) if !overflowed => m,
+            (_, Overflow::Error) if overflowed => magnitude,
+            (_, Overflow::Error) => {
+                errors.report(StrToIntError::out_of_range(
+                    negative && !negated_unsigned,
+                    T::MIN_MAGNITUDE,
+                    T::MAX_MAGNITUDE,
+                    position,
+                ))?;
+                overflowed = true;
+                magnitude
+            }
+            (_, Overflow::Saturate) => {
+                overflowed = true;
+                limit
+            }
+            (_, Overflow::Wrap) => {
+                overflowed = true;
+                magnitude
+                    .wrapping_mul(u128::from(multiplier))
+                    .wrapping_add(u128::from(digit))
+            }
+        };
+        digit_seen = true;
+        pending_underscore = None;
+    }
+    Ok(Digits {
+        magnitude,
+        negative,
+        overflowed,
+        digit_seen,
+        pending_underscore,
+        end,
+    })
+}
+
+/// Check whatever follows the digits found by [`scan_digits()`]: a trailing
+/// underscore, an integer type suffix, a `BigInt` suffix, or trailing
+/// characters.  Returns the position at which the integer ends.
+fn scan_tail<T: Prim>(
+    input: Input<'_>,
+    head: &Head<'_>,
+    digits: &Digits,
+    options: &ParseOptions,
+    partial: bool,
+    errors: &mut Errors<'_>,
+) -> Result<usize, StrToIntError> {
+    let mut end = digits.end;
+    match digits.pending_underscore {
+        Some(position)
+            if matches!(
+                head.underscores,
+                Underscores::BetweenDigits | Underscores::Python
+            ) =>
+        {
+            if partial {
+                end = position;
+            } else {
+                errors.report(StrToIntError::InvalidCharacter {
+                    c: '_',
+                    position,
+                    reason: InvalidReason::MisplacedUnderscore,
+                })?;
+            }
+        }
+        _ => {
+            if let Some(suffix) =
+                IntSuffix::match_start(&input.as_bytes()[end..]).filter(|_| options.type_suffixes)
+            {
+                if suffix != T::SUFFIX {
+                    errors.report(StrToIntError::SuffixMismatch {
+                        suffix,
+                        position: end,
+                    })?;
+                }
+                end += suffix.as_str().len();
+            } else if options.bigint_suffix
+                && digits.digit_seen
+                && input.as_bytes().get(end) == Some(&b'n')
+            {
+                if head.legacy_octal {
+                    errors.report(StrToIntError::InvalidCharacter {
+                        c: 'n',
+                        position: end,
+                        reason: InvalidReason::DisallowedBigIntSuffix,
+                    })?;
+                }
+                end += 1;
+            }
+        }
+    }
+    if !partial && end < head.trimmed_len {
+        // We only get here if parsing stopped at a (possible) type suffix.
+        let reason = if input.as_bytes()[end].is_ascii_alphabetic() {
+            InvalidReason::InvalidSuffix
+        } else {
+            InvalidReason::TrailingCharacter
+        };
+        errors.report(StrToIntError::InvalidCharacter {
+            c: input.char_at(end),
+            position: end,
+            reason,
+        })?;
+    }
+    Ok(end)
+}
+
+/// Find the closing `#` of a number in Ada notation whose digits (in radix
+/// `radix`) start at the start of `s`, and return the length of the digits
+/// before it.  If there is no closing `#`, return whether that should be
+/// reported; it is not if the digits contain an invalid character, which is
+/// reported instead.
+fn split_closing_hash(s: &[u8], radix: u32, partial: bool) -> Result<usize, bool> {
+    let len = if partial {
+        token_len(s)
+    } else {
+        s.len().saturating_sub(1)
+    };
+    if s.get(len) == Some(&b'#') {
+        Ok(len)
+    } else {
+        Err(partial
+            || s.iter()
+                .all(|&b| b == b'_' || char::from(b).is_digit(radix)))
+    }
+}
+
+/// Return the radix of the number with a leading zero at the start of `s`
+/// under [`ParseOptions::leading_zero_octal()`]: 8, unless
+/// [`ParseOptions::javascript_octal()`] is set and an `8` or `9` appears
+/// among the digits, in which case it is 10
+fn leading_zero_radix(s: &[u8], options: &ParseOptions) -> u32 {
+    if options.javascript_octal
+        && s.iter()
+            .take_while(|b| b.is_ascii_digit())
+            .any(|&b| b >= b'8')
+    {
+        10
+    } else {
+        8
+    }
+}
+
+/// Return the multipliers for the digits of the base-60 component at the
+/// start of `s`, which follows a colon, or `None` if it is not a valid
+/// component.  A component of one digit multiplies the value so far by 60;
+/// one of two digits multiplies it by 6 and then by 10.
+fn sexagesimal_multipliers(s: &[u8]) -> Option<&'static [u32]> {
+    let run = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    match (run, s.first()) {
+        (1, _) => Some(&[60]),
+        (2, Some(b'0'..=b'5')) => Some(&[6, 10]),
+        _ => None,
+    }
+}
+
+/// Return the largest value that fits in `width` bits
+fn width_limit(width: u32) -> u128 {
+    u128::MAX
+        .checked_shr(128u32.saturating_sub(width))
+        .unwrap_or(0)
+}
+
+/// If [`ParseOptions::radix_suffixes`] is enabled and `s` ends in a radix
+/// suffix letter preceded by at least one other character, return the radix
+/// denoted by the suffix and the length of the text before it.  When
+/// `partial` is true, only the run of ASCII letters, digits, and underscores
+/// at the start of `s` is examined.
+///
+/// A letter that is a valid digit in the default radix (such as `b` and `d`
+/// when the default radix is 16) is treated as a digit rather than a suffix.
+fn radix_suffix(s: &[u8], partial: bool, options: &ParseOptions) -> Option<(u32, usize)> {
+    if !options.radix_suffixes {
+        return None;
+    }
+    let len = if partial { token_len(s) } else { s.len() };
+    let (&last, rest) = s[..len].split_last()?;
+    if rest.is_empty() || char::from(last).is_digit(options.default_radix) {
+        return None;
+    }
+    let radix = match last.to_ascii_lowercase() {
+        b'h' => 16,
+        b'd' | b't' => 10,
+        b'o' | b'q' => 8,
+        b'b' | b'y' => 2,
+        _ => return None,
+    };
+    Some((radix, rest.len()))
+}
+
+/// Test whether `b` is a whitespace character in the "C" locale, as
+/// determined by C's `isspace()`
+fn is_c_space(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')
+}
+
+/// Test whether `c` is whitespace to skip.  The characters of
+/// [`is_c_space()`] are always whitespace; those of [`is_unicode_space()`]
+/// are only whitespace if `unicode` is true.
+fn is_space(c: char, unicode: bool) -> bool {
+    u8::try_from(c).is_ok_and(is_c_space) || (unicode && is_unicode_space(c))
+}
+
+/// Return the length of the run of ASCII letters, digits, and underscores at
+/// the start of `s`
+fn token_len(s: &[u8]) -> usize {
+    s.iter()
+        .position(|b| !(b.is_ascii_alphanumeric() || *b == b'_'))
+        .unwrap_or(s.len())
+}
+
+/// A radix specification in one of the notations of [`HashRadix`]
+#[derive(Clone, Copy, Debug)]
+struct HashSpec {
+    /// The radix, which may be out of range; a radix too large for a `u32` is
+    /// saturated
+    radix: u32,
+
+    /// The offset of the radix specification's decimal digits
+    digits_at: usize,
+
+    /// The length of the radix specification, including any `#`, `r`, or
+    /// letter
+    len: usize,
+}
+
+impl HashSpec {
+    /// Check that the radix is in range, given that the specification starts
+    /// at `offset`, and return it.  If errors are being collected, an invalid
+    /// radix is replaced with 36 so that scanning can continue.
+    fn check(&self, offset: usize, errors: &mut Errors<'_>) -> Result<u32, StrToIntError> {
+        if (2..=36).contains(&self.radix) {
+            Ok(self.radix)
+        } else {
+            errors.report(StrToIntError::InvalidRadix {
+                radix: self.radix,
+                position: offset + self.digits_at,
+            })?;
+            Ok(36)
+        }
+    }
+}
+
+/// Parse the run of ASCII decimal digits at the start of `s`, returning its
+/// value (saturated to `u32::MAX`) and its length
+pub(crate) fn decimal_run(s: &[u8]) -> (u32, usize) {
+    let len = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    let value = s[..len].iter().fold(0u32, |acc, &b| {
+        acc.saturating_mul(10).saturating_add(u32::from(b - b'0'))
+    });
+    (value, len)
+}
+
+/// If `s` starts with a radix specification of the form `radix#`, return it
+fn infix_spec(s: &[u8]) -> Option<HashSpec> {
+    let (radix, len) = decimal_run(s);
+    (len > 0 && s.get(len) == Some(&b'#')).then_some(HashSpec {
+        radix,
+        digits_at: 0,
+        len: len + 1,
+    })
+}
+
+/// If `s` starts with a Common Lisp radix specification (`#x`, `#o`, `#b`,
+/// or `#radixr`, case insensitive), return it
+fn lisp_spec(s: &[u8]) -> Option<HashSpec> {
+    let rest = s.strip_prefix(b"#")?;
+    let radix = match rest.first()?.to_ascii_lowercase() {
+        b'x' => 16,
+        b'o' => 8,
+        b'b' => 2,
+        _ => {
+            let (radix, len) = decimal_run(rest);
+            return (len > 0 && matches!(rest.get(len), Some(b'r' | b'R'))).then_some(HashSpec {
+                radix,
+                digits_at: 1,
+                len: len + 2,
+            });
+        }
+    };
+    Some(HashSpec {
+        radix,
+        digits_at: 1,
+        len: 2,
+    })
+}
+
+/// Convert the result of parsing a primitive integer type to the result for
+/// the corresponding `NonZero` type, given the `NonZero` type's `new()`
+/// constructor and its value for one.
+///
+/// A zero that was produced by saturating a negative value for an unsigned
+/// type is clamped to `one`, the least positive value; any other zero is an
+/// error.
+pub(crate) fn to_nonzero<T, N, F>(
+    parsed: Parsed<T>,
+    options: &ParseOptions,
+    new: F,
+    one: N,
+) -> Result<Parsed<N>, StrToIntError>
+where
+    F: FnOnce(T) -> Option<N>,
+{
+    let value = match new(parsed.value) {
+        Some(value) => value,
+        None if parsed.overflowed && options.overflow == Overflow::Saturate => one,
+        None => return Err(StrToIntError::Zero),
+    };
+    Ok(Parsed {
+        value,
+        len: parsed.len,
+        overflowed: parsed.overflowed,
+    })
+}
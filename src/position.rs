@@ -0,0 +1,212 @@
+use crate::StrToIntError;
+use core::fmt;
+
+/// Units in which a position in a string can be measured
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PositionUnit {
+    /// Bytes of UTF-8.  This is the unit used by the `position` fields of
+    /// [`StrToIntError`].
+    #[default]
+    Bytes,
+
+    /// Unicode scalar values (i.e., Rust `char`s)
+    Chars,
+
+    /// UTF-16 code units, as used by JavaScript strings and the Language
+    /// Server Protocol
+    Utf16,
+}
+
+impl PositionUnit {
+    /// Convert the byte offset `position` in `s` to this unit.
+    ///
+    /// Returns `None` if `position` is greater than the length of `s` or does
+    /// not lie on a `char` boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::PositionUnit;
+    ///
+    /// let s = "π=3.14159";
+    /// assert_eq!(PositionUnit::Bytes.convert(s, 4), Some(4));
+    /// assert_eq!(PositionUnit::Chars.convert(s, 4), Some(3));
+    /// assert_eq!(PositionUnit::Utf16.convert(s, 4), Some(3));
+    /// assert_eq!(PositionUnit::Chars.convert(s, 1), None);
+    /// ```
+    pub fn convert(self, s: &str, position: usize) -> Option<usize> {
+        let before = s.get(..position)?;
+        Some(match self {
+            PositionUnit::Bytes => position,
+            PositionUnit::Chars => before.chars().count(),
+            PositionUnit::Utf16 => before.chars().map(char::len_utf16).sum(),
+        })
+    }
+}
+
+/// A line number & column number in a string, both 1-based
+///
+/// Lines are separated by line feeds (`'\n'`), and the column is measured in
+/// a chosen [`PositionUnit`].  Note that the Language Server Protocol uses
+/// 0-based line & column numbers, so one must be subtracted from each field to
+/// get an LSP position.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LineColumn {
+    /// The 1-based line number
+    pub line: usize,
+
+    /// The 1-based column number within the line
+    pub column: usize,
+}
+
+impl LineColumn {
+    /// Compute the line & column of the byte offset `position` in `s`, with
+    /// the column measured in `unit`.
+    ///
+    /// Returns `None` if `position` is greater than the length of `s` or does
+    /// not lie on a `char` boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{LineColumn, PositionUnit};
+    ///
+    /// let s = "mode = \"dé\"\ntimeout = 3O\n";
+    /// assert_eq!(
+    ///     LineColumn::new(s, 24, PositionUnit::Bytes),
+    ///     Some(LineColumn { line: 2, column: 12 })
+    /// );
+    /// assert_eq!(
+    ///     LineColumn::new(s, 11, PositionUnit::Bytes),
+    ///     Some(LineColumn { line: 1, column: 12 })
+    /// );
+    /// assert_eq!(
+    ///     LineColumn::new(s, 11, PositionUnit::Chars),
+    ///     Some(LineColumn { line: 1, column: 11 })
+    /// );
+    /// ```
+    pub fn new(s: &str, position: usize, unit: PositionUnit) -> Option<LineColumn> {
+        let before = s.get(..position)?;
+        let (line, line_start) = match before.rfind('\n') {
+            Some(i) => (before.matches('\n').count() + 1, i + 1),
+            None => (1, 0),
+        };
+        let column = unit.convert(&s[line_start..], position - line_start)? + 1;
+        Some(LineColumn { line, column })
+    }
+}
+
+impl fmt::Display for LineColumn {
+    /// Displays the line & column in the form `{line}:{column}`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl StrToIntError {
+    /// Return the byte offset in the input at which the error occurred, if
+    /// the error has one.
+    ///
+    /// This is the `position` field of the variants that have one; for
+    /// `NoDigits` and `Zero`, `None` is returned.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            StrToIntError::InvalidCharacter { position, .. }
+            | StrToIntError::OutOfRange { position, .. }
+            | StrToIntError::SuffixMismatch { position, .. }
+            | StrToIntError::InvalidRadix { position, .. }
+            | StrToIntError::ExceedsWidth { position, .. }
+            | StrToIntError::UnknownDigit { position, .. } => Some(*position),
+            StrToIntError::NoDigits | StrToIntError::Zero => None,
+        }
+    }
+
+    /// Return a copy of the error with `offset` added to its position, if it
+    /// has one.
+    ///
+    /// When an integer is parsed from a substring of a larger document, this
+    /// can be used to convert the error's position to a byte offset in the
+    /// whole document, which can then be passed to [`position_in()`] or
+    /// [`line_column()`].  It can likewise be used to make the positions in
+    /// errors yielded by [`find_iter()`](crate::find_iter()) relative to the
+    /// start of the haystack.
+    ///
+    /// [`position_in()`]: StrToIntError::position_in()
+    /// [`line_column()`]: StrToIntError::line_column()
+    #[must_use]
+    pub fn with_offset(mut self, offset: usize) -> StrToIntError {
+        match &mut self {
+            StrToIntError::InvalidCharacter { position, .. }
+            | StrToIntError::OutOfRange { position, .. }
+            | StrToIntError::SuffixMismatch { position, .. }
+            | StrToIntError::InvalidRadix { position, .. }
+            | StrToIntError::ExceedsWidth { position, .. }
+            | StrToIntError::UnknownDigit { position, .. } => *position += offset,
+            StrToIntError::NoDigits | StrToIntError::Zero => (),
+        }
+        self
+    }
+
+    /// Return the position in `input` at which the error occurred, measured
+    /// in `unit`.
+    ///
+    /// `input` must be the string that produced the error, or else the error
+    /// must first be adjusted with [`with_offset()`] to be relative to
+    /// `input`.  (Unless [`ParseOptions::unicode_digits()`] or
+    /// [`ParseOptions::unicode_whitespace()`] is enabled, every character that
+    /// can precede an error in a valid integer is ASCII, and so all units give
+    /// the same result when `input` is just the integer string itself.  With
+    /// [`ParseOptions::python()`], by contrast, `"١٢x"` fails at byte 4,
+    /// which is character 2.)  Returns `None` if the error does not have a
+    /// position or if the position is not valid for `input`.
+    ///
+    /// [`with_offset()`]: StrToIntError::with_offset()
+    /// [`ParseOptions::unicode_digits()`]: crate::ParseOptions::unicode_digits()
+    /// [`ParseOptions::unicode_whitespace()`]: crate::ParseOptions::unicode_whitespace()
+    /// [`ParseOptions::python()`]: crate::ParseOptions::python()
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint, PositionUnit};
+    ///
+    /// let doc = "π≈3, ℯ≈2, ½≈0x1G";
+    /// let start = doc.rfind('≈').unwrap() + '≈'.len_utf8();
+    /// let e = strtoint::<u32>(&doc[start..]).unwrap_err().with_offset(start);
+    /// assert_eq!(e.position(), Some(25));
+    /// assert_eq!(e.position_in(doc, PositionUnit::Chars), Some(15));
+    /// ```
+    pub fn position_in(&self, input: &str, unit: PositionUnit) -> Option<usize> {
+        unit.convert(input, self.position()?)
+    }
+
+    /// Return the line & column in `input` at which the error occurred, with
+    /// the column measured in `unit`.
+    ///
+    /// As with [`position_in()`], `input` must be the string that produced
+    /// the error, or else the error must first be adjusted with
+    /// [`with_offset()`].  Returns `None` if the error does not have a
+    /// position or if the position is not valid for `input`.
+    ///
+    /// [`position_in()`]: StrToIntError::position_in()
+    /// [`with_offset()`]: StrToIntError::with_offset()
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint, LineColumn, PositionUnit};
+    ///
+    /// let doc = "name = \"Zoë\"\nport = 8O80\n";
+    /// let start = doc.find("8O80").unwrap();
+    /// let e = strtoint::<u16>(&doc[start..start + 4])
+    ///     .unwrap_err()
+    ///     .with_offset(start);
+    /// assert_eq!(
+    ///     e.line_column(doc, PositionUnit::Utf16),
+    ///     Some(LineColumn { line: 2, column: 9 })
+    /// );
+    /// ```
+    pub fn line_column(&self, input: &str, unit: PositionUnit) -> Option<LineColumn> {
+        LineColumn::new(input, self.position()?, unit)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{BasePrefix, LeadingZeros, ParseOptions, Underscores};
+
+/// The base prefixes recognized by Python's `int()`
+const PYTHON_PREFIXES: &[BasePrefix] = &[
+    BasePrefix::HEX.ignore_case(true),
+    BasePrefix::OCTAL.ignore_case(true),
+    BasePrefix::BINARY.ignore_case(true),
+];
+
+impl ParseOptions {
+    /// Create a `ParseOptions` that accepts the same syntax as Python's
+    /// `int(s, 0)` for a `str` argument (or, when parsing bytes, a `bytes`
+    /// argument).
+    ///
+    /// Specifically:
+    ///
+    /// - Leading and trailing whitespace is skipped, including the other
+    ///   whitespace recognized by Python (see
+    ///   [`ParseOptions::unicode_whitespace()`]).
+    ///
+    /// - An optional `+` or `-` sign may follow the leading whitespace.
+    ///
+    /// - The base prefixes `0x`, `0o`, and `0b` are matched
+    ///   case-insensitively.
+    ///
+    /// - Underscores follow [`Underscores::Python`], so `0x_1F` and `1_000`
+    ///   are valid but `1__000` and `1_` are not.
+    ///
+    /// - A decimal number may not have leading zeros unless all of its
+    ///   digits are zero (see [`LeadingZeros::AllZeros`]).
+    ///
+    /// - Non-ASCII decimal digits are accepted (see
+    ///   [`ParseOptions::unicode_digits()`]).
+    ///
+    /// Python's integers are unbounded, so an out-of-range value is an error
+    /// here where Python would succeed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, InvalidReason, ParseOptions, StrToIntError};
+    ///
+    /// let opts = ParseOptions::python();
+    /// assert_eq!(strtoint_with::<i32>(" 0X_1f\n", &opts).unwrap(), 31);
+    /// assert_eq!(strtoint_with::<i32>("-0o17", &opts).unwrap(), -15);
+    /// assert_eq!(strtoint_with::<i32>("1_000", &opts).unwrap(), 1000);
+    /// assert_eq!(strtoint_with::<i32>("0_0", &opts).unwrap(), 0);
+    /// assert_eq!(strtoint_with::<i32>("١٢", &opts).unwrap(), 12);
+    /// assert_eq!(
+    ///     strtoint_with::<i32>("0644", &opts),
+    ///     Err(StrToIntError::InvalidCharacter {
+    ///         c: '0',
+    ///         position: 0,
+    ///         reason: InvalidReason::LeadingZero
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     strtoint_with::<i32>("0x__1", &opts),
+    ///     Err(StrToIntError::InvalidCharacter {
+    ///         c: '_',
+    ///         position: 3,
+    ///         reason: InvalidReason::LeadingUnderscore
+    ///     })
+    /// );
+    /// ```
+    pub const fn python() -> ParseOptions {
+        ParseOptions::new()
+            .leading_whitespace(true)
+            .trailing_whitespace(true)
+            .unicode_whitespace(true)
+            .prefixes(PYTHON_PREFIXES)
+            .underscores(Underscores::Python)
+            .leading_zeros(LeadingZeros::AllZeros)
+            .unicode_digits(true)
+    }
+}
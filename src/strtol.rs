@@ -0,0 +1,115 @@
+use crate::{BasePrefix, Overflow, ParseOptions, PrefixRule, StrToInt, Underscores};
+
+/// The base prefixes recognized by C's `strtol()`
+const C_PREFIXES: &[BasePrefix] = &[BasePrefix::HEX.ignore_case(true)];
+
+impl ParseOptions {
+    /// Create a `ParseOptions` that accepts the same syntax as C's
+    /// `strtol()`, `strtoul()`, and related functions when called with the
+    /// given base in the "C" locale.
+    ///
+    /// Specifically, leading whitespace is skipped, an optional `+` or `-`
+    /// sign follows, and underscores are not allowed.  A `-` sign for an
+    /// unsigned type negates the value modulo 2<sup>N</sup> (see
+    /// [`ParseOptions::negate_unsigned()`]).  The base determines how the
+    /// digits are read:
+    ///
+    /// - If `base` is 0, a number starting with `0x` or `0X` is hexadecimal,
+    ///   any other number starting with `0` is octal, and all other numbers
+    ///   are decimal.
+    ///
+    /// - If `base` is 16, the digits may be preceded by `0x` or `0X`.
+    ///
+    /// - For all other bases, there is no prefix.
+    ///
+    /// As with `strtol()`, out-of-range values are errors by default; use
+    /// [`Overflow::Saturate`](crate::Overflow::Saturate) to clamp them to the
+    /// type's minimum or maximum instead, as `strtol()` does when it sets
+    /// `errno` to `ERANGE`.  To parse the start of a string and find where
+    /// the number ends, as `strtol()` does, use these options with
+    /// [`strtoint_prefix_with()`](crate::strtoint_prefix_with()) or
+    /// [`strtoint_prefix_overflowing_with()`](crate::strtoint_prefix_overflowing_with()),
+    /// or see [`strtoint_c()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is not 0 or in the range `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, Overflow, ParseOptions};
+    ///
+    /// let opts = ParseOptions::strtol(0);
+    /// assert_eq!(strtoint_with::<i32>("  0x1F", &opts).unwrap(), 31);
+    /// assert_eq!(strtoint_with::<i32>("\t-017", &opts).unwrap(), -15);
+    /// assert_eq!(strtoint_with::<i32>("42", &opts).unwrap(), 42);
+    /// assert_eq!(strtoint_with::<u16>("-1", &opts).unwrap(), 65535);
+    /// assert!(strtoint_with::<i32>("1_000", &opts).is_err());
+    /// assert!(strtoint_with::<i8>("128", &opts).is_err());
+    ///
+    /// let clamp = opts.overflow(Overflow::Saturate);
+    /// assert_eq!(strtoint_with::<i8>("128", &clamp).unwrap(), 127);
+    /// assert_eq!(strtoint_with::<i8>("-0x200", &clamp).unwrap(), -128);
+    /// ```
+    pub const fn strtol(base: u32) -> ParseOptions {
+        let opts = ParseOptions::new()
+            .leading_whitespace(true)
+            .negate_unsigned(true)
+            .prefixes(C_PREFIXES)
+            .underscores(Underscores::Forbidden);
+        match base {
+            0 => opts.leading_zero_octal(true),
+            16 => opts.default_radix(16),
+            _ => opts.default_radix(base).radix_prefix(PrefixRule::Forbidden),
+        }
+    }
+}
+
+/// Parse an integer from the start of a string in the manner of C's
+/// `strtol()` and `strtoul()`, returning the integer, the byte offset at
+/// which it ends, and whether it was out of range.
+///
+/// The syntax accepted is described under [`ParseOptions::strtol()`].  For a
+/// signed type, the result is the same as that of `strtol()` for a C `long`
+/// with as many bits as the type; for an unsigned type, it is the same as
+/// that of `strtoul()` for an `unsigned long` with as many bits as the type.
+/// As with `strtol()`, parsing stops at the first character that cannot
+/// continue the number; for example, `"0x"` and `"08"` each parse as a zero
+/// ending at offset 1.
+///
+/// As with `strtol()`, a value that is out of range for the type is clamped
+/// to the type's minimum or maximum, and the end offset still comes after
+/// all of the digits.  The returned `bool` is `true` in exactly the cases
+/// where `strtol()` would set `errno` to `ERANGE`.
+///
+/// # Errors
+///
+/// Where `strtol()` would return zero with an end pointer at the start of the
+/// string because the string does not start with a number, an error is
+/// returned instead.
+///
+/// # Panics
+///
+/// Panics if `base` is not 0 or in the range `2..=36`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::strtoint_c;
+///
+/// assert_eq!(strtoint_c::<i64>("  0x1Fz", 0).unwrap(), (31, 6, false));
+/// assert_eq!(strtoint_c::<i64>("0755 rest", 0).unwrap(), (493, 4, false));
+/// assert_eq!(strtoint_c::<i64>("0x", 0).unwrap(), (0, 1, false));
+/// assert_eq!(strtoint_c::<u32>("-1", 10).unwrap(), (u32::MAX, 2, false));
+/// assert_eq!(strtoint_c::<u32>("zz", 36).unwrap(), (1295, 2, false));
+/// assert_eq!(strtoint_c::<i8>("200;", 10).unwrap(), (127, 3, true));
+/// assert_eq!(strtoint_c::<i8>("-200", 10).unwrap(), (-128, 4, true));
+/// assert!(strtoint_c::<i64>("abc", 10).is_err());
+/// ```
+pub fn strtoint_c<T: StrToInt>(
+    s: &str,
+    base: u32,
+) -> Result<(T, usize, bool), <T as StrToInt>::Err> {
+    T::strtoint_prefix_overflowing_with(s, &ParseOptions::strtol(base).overflow(Overflow::Saturate))
+}
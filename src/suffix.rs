@@ -0,0 +1,199 @@
+use crate::{strtoint_with, ParseOptions, StrToIntError};
+use core::fmt;
+
+/// A Rust integer type suffix, as used in literals like `255u8` or `0x10_i64`
+///
+/// Suffixes are accepted when parsing if [`ParseOptions::type_suffixes()`] is
+/// enabled, and they are used to determine the type of the result of
+/// [`strtoint_typed()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IntSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+impl IntSuffix {
+    /// All integer suffixes
+    pub const ALL: [IntSuffix; 12] = [
+        IntSuffix::I8,
+        IntSuffix::I16,
+        IntSuffix::I32,
+        IntSuffix::I64,
+        IntSuffix::I128,
+        IntSuffix::Isize,
+        IntSuffix::U8,
+        IntSuffix::U16,
+        IntSuffix::U32,
+        IntSuffix::U64,
+        IntSuffix::U128,
+        IntSuffix::Usize,
+    ];
+
+    /// Return the suffix as it appears in a literal, e.g., `"u8"`
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            IntSuffix::I8 => "i8",
+            IntSuffix::I16 => "i16",
+            IntSuffix::I32 => "i32",
+            IntSuffix::I64 => "i64",
+            IntSuffix::I128 => "i128",
+            IntSuffix::Isize => "isize",
+            IntSuffix::U8 => "u8",
+            IntSuffix::U16 => "u16",
+            IntSuffix::U32 => "u32",
+            IntSuffix::U64 => "u64",
+            IntSuffix::U128 => "u128",
+            IntSuffix::Usize => "usize",
+        }
+    }
+
+    /// If `s` starts with a suffix that is not immediately followed by a
+    /// letter, digit, or underscore, return that suffix.
+    pub(crate) fn match_start(s: &[u8]) -> Option<IntSuffix> {
+        IntSuffix::ALL.into_iter().find(|sfx| {
+            s.strip_prefix(sfx.as_str().as_bytes()).is_some_and(|rest| {
+                !rest
+                    .first()
+                    .is_some_and(|&b| b.is_ascii_alphanumeric() || b == b'_')
+            })
+        })
+    }
+}
+
+impl fmt::Display for IntSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An integer whose type was determined at runtime by the suffix of the
+/// literal it was parsed from
+///
+/// This type is returned by [`strtoint_typed()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TypedInt {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+}
+
+impl TypedInt {
+    /// Return the suffix corresponding to the integer's type
+    pub const fn suffix(&self) -> IntSuffix {
+        match self {
+            TypedInt::I8(_) => IntSuffix::I8,
+            TypedInt::I16(_) => IntSuffix::I16,
+            TypedInt::I32(_) => IntSuffix::I32,
+            TypedInt::I64(_) => IntSuffix::I64,
+            TypedInt::I128(_) => IntSuffix::I128,
+            TypedInt::Isize(_) => IntSuffix::Isize,
+            TypedInt::U8(_) => IntSuffix::U8,
+            TypedInt::U16(_) => IntSuffix::U16,
+            TypedInt::U32(_) => IntSuffix::U32,
+            TypedInt::U64(_) => IntSuffix::U64,
+            TypedInt::U128(_) => IntSuffix::U128,
+            TypedInt::Usize(_) => IntSuffix::Usize,
+        }
+    }
+}
+
+impl fmt::Display for TypedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n: &dyn fmt::Display = match self {
+            TypedInt::I8(n) => n,
+            TypedInt::I16(n) => n,
+            TypedInt::I32(n) => n,
+            TypedInt::I64(n) => n,
+            TypedInt::I128(n) => n,
+            TypedInt::Isize(n) => n,
+            TypedInt::U8(n) => n,
+            TypedInt::U16(n) => n,
+            TypedInt::U32(n) => n,
+            TypedInt::U64(n) => n,
+            TypedInt::U128(n) => n,
+            TypedInt::Usize(n) => n,
+        };
+        write!(f, "{n}{}", self.suffix())
+    }
+}
+
+/// Parse an integer literal with an optional type suffix into a value of the
+/// type named by the suffix.
+///
+/// The literal is parsed according to the same rules as
+/// [`strtoint()`](crate::strtoint()), except that it may end with an integer
+/// type suffix (see [`ParseOptions::type_suffixes()`]).  The type of the
+/// result is the one named by the suffix, or `default` if there is no suffix.
+/// The result is returned along with whether the literal had a suffix, so
+/// that, e.g., `"42"` and `"42i32"` can be told apart.
+///
+/// # Errors
+///
+/// This function will return an error under the same conditions as
+/// [`strtoint()`](crate::strtoint()) for the type named by the suffix (or by
+/// `default`).  An unrecognized suffix is reported as an invalid character at
+/// the start of the suffix.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_typed, IntSuffix, TypedInt};
+///
+/// assert_eq!(
+///     strtoint_typed("255u8", IntSuffix::I32).unwrap(),
+///     (TypedInt::U8(255), true)
+/// );
+/// assert_eq!(
+///     strtoint_typed("-0x10_i64", IntSuffix::I32).unwrap(),
+///     (TypedInt::I64(-16), true)
+/// );
+/// assert_eq!(
+///     strtoint_typed("42", IntSuffix::I32).unwrap(),
+///     (TypedInt::I32(42), false)
+/// );
+/// assert_eq!(
+///     strtoint_typed("42i32", IntSuffix::I32).unwrap(),
+///     (TypedInt::I32(42), true)
+/// );
+/// assert!(strtoint_typed("256u8", IntSuffix::I32).is_err());
+/// ```
+pub fn strtoint_typed(s: &str, default: IntSuffix) -> Result<(TypedInt, bool), StrToIntError> {
+    let suffix = IntSuffix::ALL
+        .into_iter()
+        .find(|sfx| s.ends_with(sfx.as_str()));
+    let opts = ParseOptions::new().type_suffixes(true);
+    let value = match suffix.unwrap_or(default) {
+        IntSuffix::I8 => strtoint_with(s, &opts).map(TypedInt::I8),
+        IntSuffix::I16 => strtoint_with(s, &opts).map(TypedInt::I16),
+        IntSuffix::I32 => strtoint_with(s, &opts).map(TypedInt::I32),
+        IntSuffix::I64 => strtoint_with(s, &opts).map(TypedInt::I64),
+        IntSuffix::I128 => strtoint_with(s, &opts).map(TypedInt::I128),
+        IntSuffix::Isize => strtoint_with(s, &opts).map(TypedInt::Isize),
+        IntSuffix::U8 => strtoint_with(s, &opts).map(TypedInt::U8),
+        IntSuffix::U16 => strtoint_with(s, &opts).map(TypedInt::U16),
+        IntSuffix::U32 => strtoint_with(s, &opts).map(TypedInt::U32),
+        IntSuffix::U64 => strtoint_with(s, &opts).map(TypedInt::U64),
+        IntSuffix::U128 => strtoint_with(s, &opts).map(TypedInt::U128),
+        IntSuffix::Usize => strtoint_with(s, &opts).map(TypedInt::Usize),
+    }?;
+    Ok((value, suffix.is_some()))
+}
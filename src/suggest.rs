@@ -0,0 +1,205 @@
+use crate::{IntSuffix, ParseOptions, StrToInt, StrToIntError};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Suggest a correction for an integer string that failed to parse.
+///
+/// Given an input string and the error that resulted from parsing it as a `T`
+/// with [`strtoint()`](crate::strtoint()), this function checks whether the
+/// input looks like one of several common mistakes (see [`Mistake`]) and, if
+/// so, returns a corrected version of the input.  A correction is only
+/// returned if it parses successfully as a `T`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint, suggest, Mistake};
+///
+/// let s = "0X1F";
+/// let e = strtoint::<u32>(s).unwrap_err();
+/// let sugg = suggest::<u32>(s, &e).unwrap();
+/// assert_eq!(sugg.replacement(), "0x1F");
+/// assert_eq!(sugg.mistake(), Mistake::UppercasePrefix);
+/// assert_eq!(
+///     sugg.to_string(),
+///     "did you mean `0x1F`? (base prefixes must be lowercase)"
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn suggest<T: StrToInt>(input: &str, error: &StrToIntError) -> Option<Suggestion> {
+    suggest_with::<T>(input, error, &ParseOptions::new())
+}
+
+/// Suggest a correction for an integer string that failed to parse with the
+/// given options.
+///
+/// This is the [`ParseOptions`]-accepting equivalent of [`suggest()`].  A
+/// correction is only returned if it parses successfully as a `T` with
+/// `options`.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn suggest_with<T: StrToInt>(
+    input: &str,
+    error: &StrToIntError,
+    options: &ParseOptions,
+) -> Option<Suggestion> {
+    let StrToIntError::InvalidCharacter { c, position, .. } = *error else {
+        return None;
+    };
+    let (sign, body) = match input.as_bytes().first() {
+        Some(b'+' | b'-') => input.split_at(1),
+        _ => ("", input),
+    };
+    let (before, after) = (input.get(..position)?, input.get(position..)?);
+    let mut candidates = Vec::new();
+    if before.len() == sign.len() + 1 && body.starts_with('0') {
+        let rest = after.get(c.len_utf8()..).unwrap_or_default();
+        match c {
+            'X' | 'O' | 'B' => candidates.push(Suggestion::new(
+                Mistake::UppercasePrefix,
+                format!("{before}{}{rest}", c.to_ascii_lowercase()),
+            )),
+            'h' | 'H' => candidates.push(Suggestion::new(
+                Mistake::UnsupportedPrefix,
+                format!("{sign}0x{rest}"),
+            )),
+            'd' | 'D' => candidates.push(Suggestion::new(
+                Mistake::UnsupportedPrefix,
+                format!("{sign}{rest}"),
+            )),
+            _ => (),
+        }
+    }
+    if IntSuffix::ALL.into_iter().any(|sfx| after == sfx.as_str()) {
+        candidates.push(Suggestion::new(
+            Mistake::TypeSuffix,
+            before.trim_end_matches('_').into(),
+        ));
+    }
+    if matches!(c, 'O' | 'o' | 'l' | 'I') {
+        let digits = after
+            .chars()
+            .map(|ch| match ch {
+                'O' | 'o' => '0',
+                'l' | 'I' => '1',
+                ch => ch,
+            })
+            .collect::<String>();
+        candidates.push(Suggestion::new(
+            Mistake::LetterForDigit,
+            format!("{before}{digits}"),
+        ));
+    }
+    if c.is_ascii_hexdigit()
+        && !matches!(body.get(..2), Some("0x" | "0o" | "0b"))
+        && body.chars().all(|ch| ch.is_ascii_hexdigit() || ch == '_')
+    {
+        candidates.push(Suggestion::new(
+            Mistake::MissingHexPrefix,
+            format!("{sign}0x{body}"),
+        ));
+    }
+    if matches!(c, ',' | '\'' | ' ') && is_digit_groups(body, c) {
+        for sep in ["_", ""] {
+            candidates.push(Suggestion::new(
+                Mistake::DigitSeparator,
+                input.replace(c, sep),
+            ));
+        }
+    }
+    candidates
+        .into_iter()
+        .find(|sugg| T::strtoint_with(&sugg.replacement, options).is_ok())
+}
+
+/// Test whether `s` consists of groups of ASCII digits separated by `sep`,
+/// where the first group has one to three digits and each subsequent group
+/// has exactly three
+fn is_digit_groups(s: &str, sep: char) -> bool {
+    let mut groups = s.split(sep);
+    let is_group = |g: &str, sizes: core::ops::RangeInclusive<usize>| {
+        sizes.contains(&g.len()) && g.bytes().all(|b| b.is_ascii_digit())
+    };
+    groups.next().is_some_and(|g| is_group(g, 1..=3))
+        && s.contains(sep)
+        && groups.all(|g| is_group(g, 3..=3))
+}
+
+/// A suggested correction for an integer string that failed to parse
+///
+/// This type is returned by [`suggest()`] and [`suggest_with()`].  Its
+/// `Display` implementation produces a message of the form ``did you mean
+/// `0x1F`? (base prefixes must be lowercase)``.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Suggestion {
+    replacement: String,
+    mistake: Mistake,
+}
+
+impl Suggestion {
+    fn new(mistake: Mistake, replacement: String) -> Suggestion {
+        Suggestion {
+            replacement,
+            mistake,
+        }
+    }
+
+    /// Return the corrected input string
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Return the mistake that the correction fixes
+    pub fn mistake(&self) -> Mistake {
+        self.mistake
+    }
+
+    /// Consume the `Suggestion` and return the corrected input string
+    pub fn into_replacement(self) -> String {
+        self.replacement
+    }
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "did you mean `{}`? ({})", self.replacement, self.mistake)
+    }
+}
+
+/// Common mistakes in integer strings that [`suggest()`] can correct
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Mistake {
+    /// An uppercase base prefix, e.g., `0X1F` for `0x1F`
+    UppercasePrefix,
+    /// A `0h` (hexadecimal) or `0d` (decimal) base prefix, e.g., `0h1F` for
+    /// `0x1F` or `0d42` for `42`
+    UnsupportedPrefix,
+    /// A hexadecimal number without a base prefix, e.g., `ff00` for `0xff00`
+    MissingHexPrefix,
+    /// The letter `O` or `o` in place of the digit `0`, or the letter `l` or
+    /// `I` in place of the digit `1`, e.g., `1O0` for `100`
+    LetterForDigit,
+    /// A trailing integer type suffix, e.g., `42u32` for `42`
+    TypeSuffix,
+    /// Digits grouped in threes by commas, apostrophes, or spaces, e.g.,
+    /// `1,000` for `1_000` (or for `1000` if underscores are not allowed)
+    DigitSeparator,
+}
+
+impl fmt::Display for Mistake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Mistake::UppercasePrefix => "base prefixes must be lowercase",
+            Mistake::UnsupportedPrefix => "only `0x`, `0o`, and `0b` base prefixes are supported",
+            Mistake::MissingHexPrefix => "hexadecimal numbers require a `0x` prefix",
+            Mistake::LetterForDigit => "letters cannot be used in place of `0` or `1`",
+            Mistake::TypeSuffix => "integer type suffixes are not allowed",
+            Mistake::DigitSeparator => "digit group separators other than `_` are not allowed",
+        };
+        f.write_str(s)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{LeadingZeros, ParseOptions, Underscores};
+
+impl ParseOptions {
+    /// Create a `ParseOptions` that accepts the same syntax as integers in
+    /// TOML 1.0.
+    ///
+    /// Specifically:
+    ///
+    /// - Decimal numbers may have a leading `+` or `-` sign but may not have
+    ///   leading zeros, so `+0` and `-17` are valid but `00` and `0644` are
+    ///   not (see [`LeadingZeros::Forbidden`]).
+    ///
+    /// - The base prefixes `0x`, `0o`, and `0b` are recognized in lowercase
+    ///   only, and a number with a base prefix may not be signed (see
+    ///   [`ParseOptions::signed_prefixes()`]).  Leading zeros are allowed
+    ///   after a base prefix, and hexadecimal digits may be in either case.
+    ///
+    /// - Underscores follow [`Underscores::BetweenDigits`], so `1_000` is
+    ///   valid but `1__000`, `_1`, `1_`, and `0x_1` are not.
+    ///
+    /// TOML integers are limited to the range of `i64`, but the options may be
+    /// used with any integer type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, InvalidReason, ParseOptions, StrToIntError};
+    ///
+    /// let opts = ParseOptions::toml();
+    /// assert_eq!(strtoint_with::<i64>("+1_000", &opts).unwrap(), 1000);
+    /// assert_eq!(strtoint_with::<i64>("0x1F90", &opts).unwrap(), 8080);
+    /// assert_eq!(strtoint_with::<i64>("0o0755", &opts).unwrap(), 493);
+    /// assert_eq!(
+    ///     strtoint_with::<i64>("-0x10", &opts),
+    ///     Err(StrToIntError::InvalidCharacter {
+    ///         c: '-',
+    ///         position: 0,
+    ///         reason: InvalidReason::DisallowedSign
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     strtoint_with::<i64>("0644", &opts),
+    ///     Err(StrToIntError::InvalidCharacter {
+    ///         c: '0',
+    ///         position: 0,
+    ///         reason: InvalidReason::LeadingZero
+    ///     })
+    /// );
+    /// ```
+    pub const fn toml() -> ParseOptions {
+        ParseOptions::new()
+            .signed_prefixes(false)
+            .underscores(Underscores::BetweenDigits)
+            .leading_zeros(LeadingZeros::Forbidden)
+    }
+}
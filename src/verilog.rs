@@ -0,0 +1,162 @@
+use crate::parse::decimal_run;
+use crate::{strtoint_with, InvalidReason, ParseOptions, StrToInt};
+
+/// An integer parsed from a Verilog literal, along with the literal's
+/// declared width and signedness
+///
+/// This type is returned by [`strtoint_verilog()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct VerilogInt<T> {
+    value: T,
+    width: Option<u32>,
+    signed: bool,
+}
+
+impl<T: Copy> VerilogInt<T> {
+    /// Return the value of the literal
+    pub const fn value(&self) -> T {
+        self.value
+    }
+
+    /// Return the declared width of the literal in bits, or `None` if the
+    /// literal is unsized (e.g., `'hFF` or `42`)
+    pub const fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Return whether the literal was marked as signed with `s`, as in
+    /// `8'shFF`
+    pub const fn is_signed(&self) -> bool {
+        self.signed
+    }
+}
+
+/// Parse a Verilog integer literal, returning its value along with its
+/// declared width.
+///
+/// The literal may be a plain decimal number, such as `42`, or a based
+/// literal of the form `<width>'<s?><base><digits>`, such as `8'hFF` or
+/// `-4'sd3`, optionally preceded by a sign; see
+/// [`ParseOptions::verilog_literals()`] for the details.  Verilog's
+/// whitespace between the parts of a literal is not accepted.
+///
+/// # Errors
+///
+/// This function will return an error if the input is not a valid literal,
+/// if its value does not fit in its declared width, if it contains `x`, `z`,
+/// or `?` digits, or if its value is out of range for `T`.
+///
+/// # Example
+///
+/// ```
+/// use strtoint::{strtoint_verilog, StrToIntError};
+///
+/// let lit = strtoint_verilog::<u8>("8'hFF").unwrap();
+/// assert_eq!(lit.value(), 255);
+/// assert_eq!(lit.width(), Some(8));
+/// assert!(!lit.is_signed());
+///
+/// let lit = strtoint_verilog::<i32>("-4'sd3").unwrap();
+/// assert_eq!(lit.value(), -3);
+/// assert_eq!(lit.width(), Some(4));
+/// assert!(lit.is_signed());
+///
+/// let lit = strtoint_verilog::<u32>("'b1010").unwrap();
+/// assert_eq!(lit.value(), 10);
+/// assert_eq!(lit.width(), None);
+///
+/// assert_eq!(
+///     strtoint_verilog::<u32>("4'b1x01"),
+///     Err(StrToIntError::UnknownDigit {
+///         c: 'x',
+///         position: 4
+///     })
+/// );
+/// assert_eq!(
+///     strtoint_verilog::<u32>("4'h1F"),
+///     Err(StrToIntError::ExceedsWidth {
+///         width: 4,
+///         position: 4
+///     })
+/// );
+/// assert!(strtoint_verilog::<u8>("16'hFFFF").is_err());
+/// ```
+pub fn strtoint_verilog<T: StrToInt>(s: &str) -> Result<VerilogInt<T>, <T as StrToInt>::Err> {
+    let opts = ParseOptions::new()
+        .hex_prefix(false)
+        .octal_prefix(false)
+        .binary_prefix(false)
+        .verilog_literals(true);
+    let value = strtoint_with(s, &opts)?;
+    let spec = VerilogSpec::parse(s.strip_prefix(['+', '-']).unwrap_or(s).as_bytes());
+    Ok(VerilogInt {
+        value,
+        width: spec.and_then(|sp| sp.width),
+        signed: spec.is_some_and(|sp| sp.signed),
+    })
+}
+
+/// The width, signedness, and base of a Verilog based literal, e.g., the
+/// `8'sh` in `8'shFF`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct VerilogSpec {
+    /// The declared width (saturated to `u32::MAX` if it is too large), or
+    /// `None` if the literal is unsized
+    pub(crate) width: Option<u32>,
+
+    /// Whether the declared width is too large to fit in a `u32`
+    pub(crate) width_too_large: bool,
+
+    /// Whether the literal is signed
+    pub(crate) signed: bool,
+
+    /// The radix denoted by the base letter
+    pub(crate) radix: u32,
+
+    /// The length of the specification in bytes
+    pub(crate) len: usize,
+}
+
+impl VerilogSpec {
+    /// If `s` starts with a Verilog width, apostrophe, optional `s`, and base
+    /// letter (all case insensitive), return the specification
+    pub(crate) fn parse(s: &[u8]) -> Option<VerilogSpec> {
+        let (width, width_len) = decimal_run(s);
+        let width_too_large = s[..width_len]
+            .iter()
+            .try_fold(0u32, |acc, &b| {
+                acc.checked_mul(10)?.checked_add(u32::from(b - b'0'))
+            })
+            .is_none();
+        let rest = s[width_len..].strip_prefix(b"'")?;
+        let (signed, rest) = match rest.first() {
+            Some(b's' | b'S') => (true, &rest[1..]),
+            _ => (false, rest),
+        };
+        let radix = match rest.first()?.to_ascii_lowercase() {
+            b'b' => 2,
+            b'o' => 8,
+            b'd' => 10,
+            b'h' => 16,
+            _ => return None,
+        };
+        Some(VerilogSpec {
+            width: (width_len > 0).then_some(width),
+            width_too_large,
+            signed,
+            radix,
+            len: width_len + usize::from(signed) + 2,
+        })
+    }
+
+    /// Return the reason that the declared width is invalid, if it is
+    pub(crate) fn width_error(&self) -> Option<InvalidReason> {
+        if self.width_too_large {
+            Some(InvalidReason::WidthTooLarge)
+        } else if self.width == Some(0) {
+            Some(InvalidReason::ZeroWidth)
+        } else {
+            None
+        }
+    }
+}
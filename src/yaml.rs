@@ -0,0 +1,74 @@
+use crate::{ParseOptions, Underscores};
+
+impl ParseOptions {
+    /// Create a `ParseOptions` that accepts the same syntax as integers in
+    /// the YAML 1.2 core schema.
+    ///
+    /// Specifically:
+    ///
+    /// - Decimal numbers may have a leading `+` or `-` sign, and leading
+    ///   zeros are allowed, so `0644` is 644.
+    ///
+    /// - The base prefixes `0o` and `0x` are recognized in lowercase only,
+    ///   and a number with a base prefix may not be signed.  There is no
+    ///   binary prefix.
+    ///
+    /// - Underscores are not allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::yaml_core();
+    /// assert_eq!(strtoint_with::<i32>("0o17", &opts).unwrap(), 15);
+    /// assert_eq!(strtoint_with::<i32>("0x1F", &opts).unwrap(), 31);
+    /// assert_eq!(strtoint_with::<i32>("-0644", &opts).unwrap(), -644);
+    /// assert!(strtoint_with::<i32>("-0x1F", &opts).is_err());
+    /// assert!(strtoint_with::<i32>("1_000", &opts).is_err());
+    /// ```
+    pub const fn yaml_core() -> ParseOptions {
+        ParseOptions::new()
+            .binary_prefix(false)
+            .signed_prefixes(false)
+            .underscores(Underscores::Forbidden)
+    }
+
+    /// Create a `ParseOptions` that accepts the same syntax as the YAML 1.1
+    /// `int` type, as resolved by the `yaml` Python package.
+    ///
+    /// Specifically:
+    ///
+    /// - All numbers may have a leading `+` or `-` sign.
+    ///
+    /// - The base prefixes `0b` and `0x` are recognized in lowercase only,
+    ///   and a number with any other leading zero is octal (see
+    ///   [`ParseOptions::leading_zero_octal()`]), so `0644` is 420 and `09`
+    ///   is invalid.
+    ///
+    /// - Underscores follow [`Underscores::Rust`], so they may appear
+    ///   anywhere after the first digit or base prefix.
+    ///
+    /// - A decimal number not starting with zero may be followed by base-60
+    ///   components (see [`ParseOptions::sexagesimal()`]), so `190:20:30`
+    ///   is 685230.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use strtoint::{strtoint_with, ParseOptions};
+    ///
+    /// let opts = ParseOptions::yaml_1_1();
+    /// assert_eq!(strtoint_with::<i32>("0b1010_0111", &opts).unwrap(), 0xA7);
+    /// assert_eq!(strtoint_with::<i32>("-0x_1F", &opts).unwrap(), -31);
+    /// assert_eq!(strtoint_with::<i32>("0644", &opts).unwrap(), 420);
+    /// assert_eq!(strtoint_with::<i32>("190:20:30", &opts).unwrap(), 685_230);
+    /// assert!(strtoint_with::<i32>("0o17", &opts).is_err());
+    /// ```
+    pub const fn yaml_1_1() -> ParseOptions {
+        ParseOptions::new()
+            .octal_prefix(false)
+            .leading_zero_octal(true)
+            .sexagesimal(true)
+    }
+}
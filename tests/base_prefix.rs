@@ -0,0 +1,240 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_bytes_with, strtoint_prefix_with, strtoint_with, BasePrefix, InvalidReason,
+    OutOfRangeKind, ParseOptions, PrefixConflict, PrefixRule, StrToIntError, Underscores,
+};
+use test_case::test_case;
+
+static MIXED: [BasePrefix; 9] = [
+    BasePrefix::new("0x", 16).ignore_case(true),
+    BasePrefix::new("0b", 2).ignore_case(true),
+    BasePrefix::new("0d", 10),
+    BasePrefix::new("0", 8),
+    BasePrefix::new("$", 16),
+    BasePrefix::new("#", 16),
+    BasePrefix::new("%", 2),
+    BasePrefix::new("&H", 16).ignore_case(true),
+    BasePrefix::new("&O", 8).ignore_case(true),
+];
+
+#[test_case("0x1f", 31)]
+#[test_case("0X1F", 31; "upper_0X")]
+#[test_case("0B101", 5; "upper_0B")]
+#[test_case("0d123", 123)]
+#[test_case("017", 15)]
+#[test_case("-017", -15; "neg_017")]
+#[test_case("$FF", 255)]
+#[test_case("-$80", -128)]
+#[test_case("#ff", 255)]
+#[test_case("%1010", 10)]
+#[test_case("&HFF", 255)]
+#[test_case("&hff", 255; "lower_h")]
+#[test_case("&O17", 15)]
+#[test_case("&o17", 15; "lower_o")]
+#[test_case("42", 42)]
+#[test_case("0x_1", 1)]
+#[test_case("0", 0; "lone_zero")]
+#[test_case("-0", 0; "neg_zero")]
+#[test_case("00", 0; "double_zero")]
+#[test_case("0_", 0; "zero_underscore")]
+fn test_mixed_prefixes(s: &str, x: i32) {
+    let opts = ParseOptions::new().prefixes(&MIXED);
+    assert_eq!(strtoint_with::<i32>(s, &opts).unwrap(), x);
+}
+
+#[test_case("0o17", StrToIntError::InvalidCharacter {c: 'o', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0D123", StrToIntError::InvalidCharacter {c: 'D', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("08", StrToIntError::InvalidCharacter {c: '8', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("$", StrToIntError::NoDigits)]
+#[test_case("&HFG", StrToIntError::InvalidCharacter {c: 'G', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("-&H_G", StrToIntError::InvalidCharacter {c: 'G', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("&X1", StrToIntError::InvalidCharacter {c: '&', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("&H8000_0000", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -2147483648, max: 2147483647, position: 10})]
+fn test_mixed_prefixes_err(s: &str, err: StrToIntError) {
+    let opts = ParseOptions::new().prefixes(&MIXED);
+    assert_eq!(strtoint_with::<i32>(s, &opts).unwrap_err(), err);
+}
+
+#[test]
+fn test_prefix_conflict_position() {
+    let opts = ParseOptions::new()
+        .prefixes(&MIXED)
+        .default_radix(16)
+        .prefix_conflict(PrefixConflict::Error);
+    assert_eq!(strtoint_with::<u32>("&hff", &opts), Ok(255));
+    assert_eq!(
+        strtoint_with::<u32>("+&o17", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'o',
+            position: 2,
+            reason: InvalidReason::ConflictingPrefix
+        })
+    );
+    assert_eq!(
+        strtoint_with::<u32>("%1", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '%',
+            position: 0,
+            reason: InvalidReason::ConflictingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_non_ascii_prefix() {
+    static TABLE: [BasePrefix; 1] = [BasePrefix::new("h€", 16)];
+    let opts = ParseOptions::new()
+        .prefixes(&TABLE)
+        .default_radix(16)
+        .prefix_conflict(PrefixConflict::Error);
+    assert_eq!(strtoint_with::<u32>("h€ff", &opts), Ok(255));
+    assert_eq!(
+        strtoint_with::<u32>("-h€ff", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '-',
+            position: 0,
+            reason: InvalidReason::UnsignedMinus
+        })
+    );
+    assert_eq!(
+        strtoint_with::<i32>("-h€fg", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'g',
+            position: 6,
+            reason: InvalidReason::InvalidDigit
+        })
+    );
+    assert_eq!(
+        strtoint_with::<u32>("h€f", &opts.default_radix(2)),
+        Err(StrToIntError::InvalidCharacter {
+            c: '€',
+            position: 1,
+            reason: InvalidReason::ConflictingPrefix
+        })
+    );
+}
+
+#[test_case("0x10", 16)]
+#[test_case("0X10", 16; "upper_0X")]
+#[test_case("0xy", 0)]
+#[test_case("$10", 16)]
+fn test_prefix_partial(s: &str, x: u32) {
+    static TABLE: [BasePrefix; 2] = [
+        BasePrefix::new("0x", 16).ignore_case(true),
+        BasePrefix::new("$", 16),
+    ];
+    let opts = ParseOptions::new().prefixes(&TABLE);
+    assert_eq!(strtoint_prefix_with::<u32>(s, &opts).unwrap().0, x);
+}
+
+#[test_case("0)", 0, ")")]
+#[test_case("017 + 1", 15, " + 1")]
+#[test_case("08", 0, "8")]
+fn test_mixed_prefixes_partial(s: &str, x: u32, rest: &str) {
+    let opts = ParseOptions::new().prefixes(&MIXED);
+    assert_eq!(strtoint_prefix_with::<u32>(s, &opts).unwrap(), (x, rest));
+}
+
+#[test]
+fn test_prefix_partial_no_digits() {
+    static TABLE: [BasePrefix; 1] = [BasePrefix::new("$", 16)];
+    let opts = ParseOptions::new().prefixes(&TABLE);
+    assert_eq!(
+        strtoint_prefix_with::<u32>("$x", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 1,
+            reason: InvalidReason::InvalidDigit
+        })
+    );
+}
+
+#[test]
+fn test_prefix_bytes() {
+    static TABLE: [BasePrefix; 1] = [BasePrefix::new("&h", 16).ignore_case(true)];
+    let opts = ParseOptions::new().prefixes(&TABLE);
+    assert_eq!(strtoint_bytes_with::<u16>(b"&Hbeef", &opts), Ok(0xBEEF));
+}
+
+#[test]
+fn test_longest_match_tie() {
+    static TABLE: [BasePrefix; 2] = [BasePrefix::new("#", 16), BasePrefix::new("#", 2)];
+    let opts = ParseOptions::new().prefixes(&TABLE);
+    assert_eq!(strtoint_with::<u32>("#10", &opts), Ok(16));
+}
+
+#[test]
+fn test_empty_table() {
+    let opts = ParseOptions::new().prefixes(&[]);
+    assert_eq!(
+        strtoint_with::<u32>("0x10", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 1,
+            reason: InvalidReason::InvalidDigit
+        })
+    );
+}
+
+#[test]
+fn test_defaults_table() {
+    let opts = ParseOptions::new()
+        .hex_prefix(false)
+        .prefixes(BasePrefix::DEFAULTS);
+    assert_eq!(strtoint_with::<u32>("0x10", &opts), Ok(16));
+}
+
+#[test]
+fn test_prefix_underscores() {
+    static TABLE: [BasePrefix; 1] = [BasePrefix::new("$", 16)];
+    let opts = ParseOptions::new().prefixes(&TABLE);
+    assert_eq!(strtoint_with::<u32>("$_ff", &opts), Ok(255));
+    let opts = opts.underscores(Underscores::BetweenDigits);
+    assert_eq!(
+        strtoint_with::<u32>("$_ff", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '_',
+            position: 1,
+            reason: InvalidReason::LeadingUnderscore
+        })
+    );
+}
+
+#[test]
+fn test_prefix_required() {
+    static TABLE: [BasePrefix; 1] = [BasePrefix::new("$", 16)];
+    let opts = ParseOptions::new()
+        .prefixes(&TABLE)
+        .default_radix(16)
+        .radix_prefix(PrefixRule::Required);
+    assert_eq!(strtoint_with::<u32>("$ff", &opts), Ok(255));
+    assert_eq!(
+        strtoint_with::<u32>("ff", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'f',
+            position: 0,
+            reason: InvalidReason::MissingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_accessors() {
+    let p = BasePrefix::new("&H", 16).ignore_case(true);
+    assert_eq!(p.text(), "&H");
+    assert_eq!(p.radix(), 16);
+    assert!(p.is_case_insensitive());
+    assert!(!BasePrefix::HEX.is_case_insensitive());
+}
+
+#[test]
+#[should_panic(expected = "base prefix must not be empty")]
+fn test_empty_prefix() {
+    let _ = BasePrefix::new("", 16);
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2..=36")]
+fn test_prefix_bad_radix() {
+    let _ = BasePrefix::new("0z", 37);
+}
@@ -0,0 +1,65 @@
+#![cfg(test)]
+use core::num::{NonZeroI64, NonZeroU8};
+use strtoint::{
+    strtoint_bytes, strtoint_bytes_with, InvalidReason, OutOfRangeKind, ParseOptions, StrToInt,
+    StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case(b"0", 0)]
+#[test_case(b"-1", -1; "neg1")]
+#[test_case(b"+0x7f", 127; "pos_hex")]
+#[test_case(b"-0o200", -128; "neg_oct")]
+#[test_case(b"0b0111_1111", 127)]
+fn test_strtoint_bytes_i8(s: &[u8], x: i8) {
+    assert_eq!(strtoint_bytes::<i8>(s).unwrap(), x);
+}
+
+#[test_case(b"1", 1)]
+#[test_case(b"123_456", 123_456)]
+#[test_case(b"0xFFFF_FFFF", 4_294_967_295)]
+fn test_strtoint_bytes_u128(s: &[u8], x: u128) {
+    assert_eq!(strtoint_bytes::<u128>(s).unwrap(), x);
+}
+
+#[test_case(b"", StrToIntError::NoDigits; "empty")]
+#[test_case(b"0x", StrToIntError::NoDigits)]
+#[test_case(b"-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}; "neg1")]
+#[test_case(b"256", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
+#[test_case(b"42.0", StrToIntError::InvalidCharacter {c: '.', position: 2, reason: InvalidReason::TrailingCharacter})]
+#[test_case(b"12\xFF", StrToIntError::InvalidCharacter {c: '\u{FF}', position: 2, reason: InvalidReason::TrailingCharacter}; "high_byte")]
+#[test_case(b"\x80", StrToIntError::InvalidCharacter {c: '\u{80}', position: 0, reason: InvalidReason::UnexpectedCharacter}; "continuation_byte")]
+#[test_case("12³45".as_bytes(), StrToIntError::InvalidCharacter {c: '\u{C2}', position: 2, reason: InvalidReason::TrailingCharacter}; "utf8_super3")]
+fn test_strtoint_bytes_u8_err(s: &[u8], err: StrToIntError) {
+    assert_eq!(strtoint_bytes::<u8>(s).unwrap_err(), err);
+}
+
+#[test]
+fn test_strtoint_bytes_nonzero() {
+    assert_eq!(
+        strtoint_bytes::<NonZeroU8>(b"0xff").unwrap(),
+        NonZeroU8::new(255).unwrap()
+    );
+    assert_eq!(
+        strtoint_bytes::<NonZeroU8>(b"0").unwrap_err(),
+        StrToIntError::Zero
+    );
+    assert_eq!(
+        NonZeroI64::strtoint_bytes(b"-0x10").unwrap(),
+        NonZeroI64::new(-16).unwrap()
+    );
+}
+
+#[test]
+fn test_strtoint_bytes_with() {
+    let opts = ParseOptions::new().hex_prefix(false);
+    assert_eq!(strtoint_bytes_with::<u32>(b"0o10", &opts).unwrap(), 8);
+    assert_eq!(
+        u32::strtoint_bytes_with(b"0x10", &opts).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 1,
+            reason: InvalidReason::InvalidDigit
+        }
+    );
+}
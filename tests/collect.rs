@@ -0,0 +1,219 @@
+#![cfg(test)]
+use core::num::NonZeroU8;
+use strtoint::{
+    strtoint, strtoint_all_errors, strtoint_all_errors_with, ErrorArray, ErrorSink, IntSuffix,
+    InvalidReason, OutOfRangeKind, ParseOptions, StrToInt, StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case("0x12G4Z_", &[
+    StrToIntError::InvalidCharacter {c: 'G', position: 4, reason: InvalidReason::InvalidDigit},
+    StrToIntError::InvalidCharacter {c: 'Z', position: 6, reason: InvalidReason::InvalidDigit},
+])]
+#[test_case("-12a", &[
+    StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus},
+    StrToIntError::InvalidCharacter {c: 'a', position: 3, reason: InvalidReason::InvalidDigit},
+])]
+#[test_case("+-x", &[
+    StrToIntError::InvalidCharacter {c: '-', position: 1, reason: InvalidReason::MisplacedSign},
+    StrToIntError::InvalidCharacter {c: 'x', position: 2, reason: InvalidReason::InvalidDigit},
+    StrToIntError::NoDigits,
+])]
+#[test_case("99999999999 x9", &[
+    StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 4294967295, position: 9},
+    StrToIntError::InvalidCharacter {c: ' ', position: 11, reason: InvalidReason::TrailingCharacter},
+    StrToIntError::InvalidCharacter {c: 'x', position: 12, reason: InvalidReason::InvalidDigit},
+])]
+#[test_case("1é2😀", &[
+    StrToIntError::InvalidCharacter {c: 'é', position: 1, reason: InvalidReason::TrailingCharacter},
+    StrToIntError::InvalidCharacter {c: '😀', position: 4, reason: InvalidReason::TrailingCharacter},
+])]
+#[test_case("", &[StrToIntError::NoDigits])]
+#[test_case("0x", &[StrToIntError::NoDigits])]
+#[test_case("42", &[])]
+fn test_all_errors_u32(s: &str, expected: &[StrToIntError]) {
+    let mut errors = ErrorArray::<8>::new();
+    let r = strtoint_all_errors::<u32>(s, &mut errors);
+    assert_eq!(errors.as_slice(), expected);
+    assert_eq!(r.err(), expected.first().copied());
+}
+
+#[test_case("0x12G4Z_")]
+#[test_case("-12a")]
+#[test_case("+-x")]
+#[test_case("99999999999 x9")]
+#[test_case("1é2😀")]
+#[test_case("_1__2")]
+#[test_case(" 0b102 ")]
+#[test_case("0x-1")]
+#[test_case("")]
+#[test_case("-")]
+#[test_case("256")]
+#[test_case("0")]
+fn test_first_error_matches_strtoint(s: &str) {
+    let mut errors = ErrorArray::<8>::new();
+    let r = strtoint_all_errors::<NonZeroU8>(s, &mut errors);
+    assert_eq!(r, strtoint::<NonZeroU8>(s));
+    assert_eq!(errors.as_slice().first().copied(), r.err());
+}
+
+#[test]
+fn test_all_errors_nonzero_zero() {
+    let mut errors = ErrorArray::<8>::new();
+    assert_eq!(
+        strtoint_all_errors::<NonZeroU8>("0", &mut errors),
+        Err(StrToIntError::Zero)
+    );
+    assert_eq!(errors.as_slice(), [StrToIntError::Zero]);
+}
+
+#[test]
+fn test_all_errors_nonzero_zero_with_other_errors() {
+    let mut errors = ErrorArray::<8>::new();
+    assert!(strtoint_all_errors::<NonZeroU8>("0?", &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [StrToIntError::InvalidCharacter {
+            c: '?',
+            position: 1,
+            reason: InvalidReason::TrailingCharacter
+        }]
+    );
+}
+
+#[test]
+fn test_all_errors_suffix_mismatch() {
+    let opts = ParseOptions::new().type_suffixes(true);
+    let mut errors = ErrorArray::<8>::new();
+    assert!(strtoint_all_errors_with::<u8>("-1u16", &opts, &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [
+            StrToIntError::InvalidCharacter {
+                c: '-',
+                position: 0,
+                reason: InvalidReason::UnsignedMinus
+            },
+            StrToIntError::SuffixMismatch {
+                suffix: IntSuffix::U16,
+                position: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_all_errors_ok() {
+    let mut errors = ErrorArray::<8>::new();
+    assert_eq!(strtoint_all_errors::<i16>("-0o17", &mut errors), Ok(-15));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_error_array_full() {
+    let mut errors = ErrorArray::<2>::new();
+    assert!(strtoint_all_errors::<u8>("x?y!", &mut errors).is_err());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.discarded(), 3);
+    assert_eq!(
+        format!("{errors:?}"),
+        concat!(
+            "ErrorArray { errors: [",
+            "InvalidCharacter { c: 'x', position: 0, reason: InvalidDigit }, ",
+            "InvalidCharacter { c: '?', position: 1, reason: UnexpectedCharacter }",
+            "], discarded: 3 }",
+        )
+    );
+    errors.clear();
+    assert!(errors.is_empty());
+    assert_eq!(errors.discarded(), 0);
+    assert_eq!(errors, ErrorArray::new());
+}
+
+#[test]
+fn test_custom_sink() {
+    struct Counter(usize);
+
+    impl ErrorSink for Counter {
+        fn push(&mut self, _: StrToIntError) {
+            self.0 += 1;
+        }
+    }
+
+    let mut counter = Counter(0);
+    assert!(strtoint_all_errors::<u8>("1.2.3", &mut counter).is_err());
+    assert_eq!(counter.0, 2);
+}
+
+/// A type with its own error type that implements only the required methods
+/// of `StrToInt`
+#[derive(Debug, Eq, PartialEq)]
+struct Even(u32);
+
+impl StrToInt for Even {
+    type Err = &'static str;
+
+    fn strtoint_with(s: &str, options: &ParseOptions) -> Result<Even, &'static str> {
+        match u32::strtoint_with(s, options) {
+            Ok(x) if x % 2 == 0 => Ok(Even(x)),
+            Ok(_) => Err("odd"),
+            Err(_) => Err("invalid"),
+        }
+    }
+
+    fn strtoint_bytes_with(s: &[u8], options: &ParseOptions) -> Result<Even, &'static str> {
+        let s = core::str::from_utf8(s).map_err(|_| "invalid")?;
+        Even::strtoint_with(s, options)
+    }
+
+    fn strtoint_prefix_with(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Even, usize), &'static str> {
+        match u32::strtoint_prefix_with(s, options) {
+            Ok((x, len)) if x % 2 == 0 => Ok((Even(x), len)),
+            Ok(_) => Err("odd"),
+            Err(_) => Err("invalid"),
+        }
+    }
+}
+
+#[test]
+fn test_all_errors_default_impl() {
+    let mut errors = ErrorArray::<4>::new();
+    assert_eq!(strtoint_all_errors::<Even>("42", &mut errors), Ok(Even(42)));
+    assert_eq!(strtoint_all_errors::<Even>("43", &mut errors), Err("odd"));
+    assert_eq!(
+        strtoint_all_errors::<Even>("4x4", &mut errors),
+        Err("invalid")
+    );
+    assert!(errors.as_slice().is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_all_errors_vec() {
+    let mut errors = Vec::new();
+    assert!(strtoint_all_errors::<i8>("1,000,000", &mut errors).is_err());
+    assert_eq!(
+        errors,
+        [
+            StrToIntError::InvalidCharacter {
+                c: ',',
+                position: 1,
+                reason: InvalidReason::TrailingCharacter
+            },
+            StrToIntError::OutOfRange {
+                kind: OutOfRangeKind::TooLarge,
+                min: -128,
+                max: 127,
+                position: 4
+            },
+            StrToIntError::InvalidCharacter {
+                c: ',',
+                position: 5,
+                reason: InvalidReason::TrailingCharacter
+            },
+        ]
+    );
+}
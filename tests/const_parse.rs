@@ -0,0 +1,112 @@
+#![cfg(test)]
+use strtoint::{
+    const_parse_i128, const_parse_i16, const_parse_i32, const_parse_i64, const_parse_i8,
+    const_parse_isize, const_parse_u128, const_parse_u16, const_parse_u32, const_parse_u64,
+    const_parse_u8, const_parse_usize, strtoint, InvalidReason, StrToIntError,
+};
+use test_case::test_case;
+
+const INPUTS: &[&str] = &[
+    "",
+    "0",
+    "+0",
+    "-0",
+    "1",
+    "-1",
+    "+",
+    "-",
+    "_",
+    "0x",
+    "0o",
+    "0b",
+    "0x10",
+    "-0o10",
+    "+0b10",
+    "0x___1___",
+    "1___",
+    "___1",
+    "0644",
+    "0X10",
+    "0x-1",
+    "-0x80",
+    "127",
+    "128",
+    "-128",
+    "-129",
+    "255",
+    "256",
+    "65535",
+    "65536",
+    "2147483647",
+    "-2147483649",
+    "4294967296",
+    "18446744073709551615",
+    "-9223372036854775809",
+    "170141183460469231731687303715884105727",
+    "-170141183460469231731687303715884105728",
+    "340282366920938463463374607431768211455",
+    "340282366920938463463374607431768211456",
+    "0xabcd_FFFF",
+    "42.0",
+    " 42",
+    "12³45",
+    "0b102",
+    "0o78",
+    "0xfg",
+];
+
+#[test]
+fn test_const_parse_matches_strtoint() {
+    for &s in INPUTS {
+        assert_eq!(const_parse_i8(s), strtoint::<i8>(s), "input = {s:?}");
+        assert_eq!(const_parse_i16(s), strtoint::<i16>(s), "input = {s:?}");
+        assert_eq!(const_parse_i32(s), strtoint::<i32>(s), "input = {s:?}");
+        assert_eq!(const_parse_i64(s), strtoint::<i64>(s), "input = {s:?}");
+        assert_eq!(const_parse_i128(s), strtoint::<i128>(s), "input = {s:?}");
+        assert_eq!(const_parse_isize(s), strtoint::<isize>(s), "input = {s:?}");
+        assert_eq!(const_parse_u8(s), strtoint::<u8>(s), "input = {s:?}");
+        assert_eq!(const_parse_u16(s), strtoint::<u16>(s), "input = {s:?}");
+        assert_eq!(const_parse_u32(s), strtoint::<u32>(s), "input = {s:?}");
+        assert_eq!(const_parse_u64(s), strtoint::<u64>(s), "input = {s:?}");
+        assert_eq!(const_parse_u128(s), strtoint::<u128>(s), "input = {s:?}");
+        assert_eq!(const_parse_usize(s), strtoint::<usize>(s), "input = {s:?}");
+    }
+}
+
+#[test]
+fn test_const_parse_in_const() {
+    const HEX: Result<u32, StrToIntError> = const_parse_u32("0xFFFF");
+    const BAD: Result<u32, StrToIntError> = const_parse_u32("0x1G");
+    assert_eq!(HEX, Ok(0xFFFF));
+    assert_eq!(
+        BAD,
+        Err(StrToIntError::InvalidCharacter {
+            c: 'G',
+            position: 3,
+            reason: InvalidReason::InvalidDigit
+        })
+    );
+}
+
+#[test_case(strtoint::strtoint!(i64, "-0x8000_0000_0000_0000"), i64::MIN)]
+#[test_case(strtoint::strtoint!(i64, "+1_000"), 1000)]
+fn test_strtoint_macro_i64(x: i64, y: i64) {
+    assert_eq!(x, y);
+}
+
+#[test]
+fn test_strtoint_macro() {
+    const MODE: u16 = strtoint::strtoint!(u16, "0o644");
+    static MASK: u128 = strtoint::strtoint!(u128, "0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF");
+    assert_eq!(MODE, 0o644);
+    assert_eq!(MASK, u128::MAX);
+    assert_eq!(strtoint::strtoint!(i8, "-128"), i8::MIN);
+    assert_eq!(strtoint::strtoint!(i16, "0b1"), 1);
+    assert_eq!(strtoint::strtoint!(i32, "0"), 0);
+    assert_eq!(strtoint::strtoint!(i128, "-1"), -1);
+    assert_eq!(strtoint::strtoint!(isize, "-0x10"), -16);
+    assert_eq!(strtoint::strtoint!(u8, "255"), 255);
+    assert_eq!(strtoint::strtoint!(u32, "0xFFFF"), 0xFFFF);
+    assert_eq!(strtoint::strtoint!(u64, "1_2_3"), 123);
+    assert_eq!(strtoint::strtoint!(usize, "0o17"), 15);
+}
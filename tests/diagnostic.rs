@@ -0,0 +1,93 @@
+#![cfg(test)]
+use core::num::NonZeroU32;
+use strtoint::{strtoint, strtoint_with, Diagnostic, HashRadix, ParseOptions, StrToIntError};
+use test_case::test_case;
+
+fn render<T>(s: &str, result: Result<T, StrToIntError>) -> String {
+    match result {
+        Ok(_) => panic!("parsing {s:?} unexpectedly succeeded"),
+        Err(e) => Diagnostic::new(s, e).to_string(),
+    }
+}
+
+#[test_case(
+    "0x-1",
+    "invalid character '-' at position 2\n    0x-1\n      ^ sign after base prefix"
+)]
+#[test_case(
+    "½12",
+    "invalid character '½' at position 0\n    ½12\n    ^ unexpected character before digits";
+    "non_ascii_start"
+)]
+#[test_case(
+    "1²3",
+    "invalid character '²' at position 1\n    1²3\n     ^ unexpected character after digits";
+    "non_ascii_middle"
+)]
+#[test_case(
+    "12\tx",
+    "invalid character '\\t' at position 2\n    12 x\n      ^ unexpected character after digits";
+    "tab"
+)]
+#[test_case("0x", "no digits in input\n    0x\n      ^ expected digits")]
+#[test_case("", "no digits in input\n    \n    ^ expected digits"; "empty")]
+#[test_case(
+    "1_000",
+    "value is too large for numeric type (maximum: 255)\n    1_000\n        ^ value goes out of range here"
+)]
+fn test_diagnostic_u8(s: &str, rendered: &str) {
+    assert_eq!(render(s, strtoint::<u8>(s)), rendered);
+}
+
+#[test]
+fn test_diagnostic_suffix_mismatch() {
+    let s = "300u16";
+    let opts = ParseOptions::new().type_suffixes(true);
+    assert_eq!(
+        render(s, strtoint_with::<u32>(s, &opts)),
+        "integer suffix \"u16\" at position 3 does not match numeric type\n    300u16\n       ^^^ suffix does not match numeric type"
+    );
+}
+
+#[test]
+fn test_diagnostic_invalid_radix() {
+    let s = "-40#12";
+    let opts = ParseOptions::new().hash_radix(HashRadix::Erlang);
+    assert_eq!(
+        render(s, strtoint_with::<i32>(s, &opts)),
+        "radix 40 at position 1 is not between 2 and 36\n    -40#12\n     ^^ radix must be from 2 to 36"
+    );
+}
+
+#[test]
+fn test_diagnostic_zero() {
+    let s = "0x00";
+    assert_eq!(
+        render(s, strtoint::<NonZeroU32>(s)),
+        "value is zero, but numeric type is nonzero\n    0x00\n    ^^^^ value is zero"
+    );
+}
+
+#[test]
+fn test_diagnostic_mismatched_input() {
+    let e = strtoint::<u64>("12345678x").unwrap_err();
+    assert_eq!(
+        Diagnostic::new("x", e).to_string(),
+        "invalid character 'x' at position 8\n    x\n     ^ not a valid digit for the radix"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_diagnostic_color() {
+    let s = "4x";
+    let e = strtoint::<u8>(s).unwrap_err();
+    assert_eq!(
+        Diagnostic::new(s, e).color(true).to_string(),
+        "\x1B[1minvalid character 'x' at position 1\x1B[0m\n    4x\n     \x1B[1;31m^ not a valid digit for the radix\x1B[0m"
+    );
+    assert_eq!(
+        Diagnostic::new(s, e).color(false).to_string(),
+        "invalid character 'x' at position 1\n    4x\n     ^ not a valid digit for the radix"
+    );
+}
@@ -1,4 +1,4 @@
-use strtoint::StrToIntError;
+use strtoint::{IntSuffix, InvalidReason, OutOfRangeKind, StrToIntError};
 
 #[test]
 fn test_display_error_no_digits() {
@@ -10,7 +10,8 @@ fn test_display_error_invalid_character() {
     assert_eq!(
         StrToIntError::InvalidCharacter {
             c: '.',
-            position: 2
+            position: 2,
+            reason: InvalidReason::TrailingCharacter
         }
         .to_string(),
         "invalid character '.' at position 2"
@@ -18,9 +19,93 @@ fn test_display_error_invalid_character() {
 }
 
 #[test]
-fn test_display_error_out_of_range() {
+fn test_display_error_too_large() {
+    assert_eq!(
+        StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: 255,
+            position: 2
+        }
+        .to_string(),
+        "value is too large for numeric type (maximum: 255)"
+    );
+}
+
+#[test]
+fn test_display_error_too_small() {
+    assert_eq!(
+        StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooSmall,
+            min: -128,
+            max: 127,
+            position: 3
+        }
+        .to_string(),
+        "value is too small for numeric type (minimum: -128)"
+    );
+}
+
+#[test]
+fn test_display_error_suffix_mismatch() {
+    assert_eq!(
+        StrToIntError::SuffixMismatch {
+            suffix: IntSuffix::U8,
+            position: 3
+        }
+        .to_string(),
+        "integer suffix \"u8\" at position 3 does not match numeric type"
+    );
+}
+
+#[test]
+fn test_display_error_invalid_radix() {
+    assert_eq!(
+        StrToIntError::InvalidRadix {
+            radix: 37,
+            position: 1
+        }
+        .to_string(),
+        "radix 37 at position 1 is not between 2 and 36"
+    );
+}
+
+#[test]
+fn test_display_error_exceeds_width() {
+    assert_eq!(
+        StrToIntError::ExceedsWidth {
+            width: 4,
+            position: 4
+        }
+        .to_string(),
+        "value does not fit in declared width of 4 bits"
+    );
+}
+
+#[test]
+fn test_display_error_unknown_digit() {
+    assert_eq!(
+        StrToIntError::UnknownDigit {
+            c: 'x',
+            position: 3
+        }
+        .to_string(),
+        "unknown or high-impedance digit 'x' at position 3"
+    );
+}
+
+#[test]
+fn test_display_error_zero() {
+    assert_eq!(
+        StrToIntError::Zero.to_string(),
+        "value is zero, but numeric type is nonzero"
+    );
+}
+
+#[test]
+fn test_display_invalid_reason() {
     assert_eq!(
-        StrToIntError::OutOfRange.to_string(),
-        "value is out of range for numeric type"
+        InvalidReason::SignAfterPrefix.to_string(),
+        "sign after base prefix"
     );
 }
@@ -0,0 +1,75 @@
+#![cfg(test)]
+use core::ops::Range;
+use strtoint::{
+    find_iter, find_iter_with, InvalidReason, OutOfRangeKind, ParseOptions, StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case("", Vec::new(); "empty")]
+#[test_case("no numbers here", Vec::new())]
+#[test_case("42", vec![(0..2, Ok(42))])]
+#[test_case("a 1 b 2", vec![(2..3, Ok(1)), (6..7, Ok(2))])]
+#[test_case("x86_64 and i386", Vec::new())]
+#[test_case("_1 1_", vec![(3..5, Ok(1))])]
+#[test_case("1.5", vec![(0..1, Ok(1)), (2..3, Ok(5))])]
+#[test_case("(-3,+4)", vec![(1..3, Ok(-3)), (4..6, Ok(4))])]
+#[test_case("5-3", vec![(0..1, Ok(5)), (2..3, Ok(3))])]
+#[test_case("--5", vec![(1..3, Ok(-5))])]
+#[test_case("a-5", vec![(2..3, Ok(5))])]
+#[test_case("x-5 x -5", vec![(2..3, Ok(5)), (6..8, Ok(-5))])]
+#[test_case("n_-1", vec![(3..4, Ok(1))])]
+#[test_case("é1 ·2", vec![(6..7, Ok(2))]; "non_ascii")]
+#[test_case("0b101,0o17;0x7F", vec![(0..5, Ok(5)), (6..10, Ok(15)), (11..15, Ok(127))])]
+#[test_case(
+    "42px 0x1G",
+    vec![
+        (0..4, Err(StrToIntError::InvalidCharacter { c: 'p', position: 2, reason: InvalidReason::InvalidDigit })),
+        (5..9, Err(StrToIntError::InvalidCharacter { c: 'G', position: 3, reason: InvalidReason::InvalidDigit })),
+    ]
+)]
+#[test_case("999 1", vec![(0..3, Err(StrToIntError::OutOfRange { kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 2 })), (4..5, Ok(1))])]
+fn test_find_iter_i8(s: &str, matches: Vec<(Range<usize>, Result<i8, StrToIntError>)>) {
+    assert_eq!(find_iter::<i8>(s).collect::<Vec<_>>(), matches);
+}
+
+#[test]
+fn test_find_iter_unsigned_negative() {
+    assert_eq!(
+        find_iter::<u32>("errno -12").collect::<Vec<_>>(),
+        vec![(
+            6..9,
+            Err(StrToIntError::InvalidCharacter {
+                c: '-',
+                position: 0,
+                reason: InvalidReason::UnsignedMinus
+            })
+        )]
+    );
+}
+
+#[test]
+fn test_find_iter_with_no_plus_sign() {
+    let opts = ParseOptions::new().plus_sign(false).hex_prefix(false);
+    assert_eq!(
+        find_iter_with::<i32>("+4 0x10", &opts).collect::<Vec<_>>(),
+        vec![
+            (1..2, Ok(4)),
+            (
+                3..7,
+                Err(StrToIntError::InvalidCharacter {
+                    c: 'x',
+                    position: 1,
+                    reason: InvalidReason::InvalidDigit
+                })
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_find_iter_fused() {
+    let mut iter = find_iter::<u16>("7");
+    assert_eq!(iter.next(), Some((0..1, Ok(7))));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,226 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_all_errors_with, strtoint_bytes_with, strtoint_prefix_with, strtoint_with, ErrorArray,
+    HashRadix, InvalidReason, OutOfRangeKind, ParseOptions, PrefixRule, StrToIntError,
+};
+use test_case::test_case;
+
+fn erlang() -> ParseOptions {
+    ParseOptions::new().hash_radix(HashRadix::Erlang)
+}
+
+fn ada() -> ParseOptions {
+    ParseOptions::new().hash_radix(HashRadix::Ada)
+}
+
+fn lisp() -> ParseOptions {
+    ParseOptions::new().hash_radix(HashRadix::Lisp)
+}
+
+#[test_case("16#FF", 255)]
+#[test_case("16#ff", 255; "lowercase")]
+#[test_case("36#zz", 1295)]
+#[test_case("36#ZZ", 1295; "uppercase")]
+#[test_case("2#1010", 10)]
+#[test_case("8#777", 511)]
+#[test_case("10#99", 99)]
+#[test_case("3#210", 21)]
+#[test_case("016#FF", 255; "radix_leading_zero")]
+#[test_case("-16#FF", -255)]
+#[test_case("+16#FF", 255)]
+#[test_case("16#F_F", 255)]
+#[test_case("42", 42)]
+#[test_case("0x10", 16)]
+fn test_erlang(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &erlang()).unwrap(), x);
+}
+
+#[test_case("16#FF#", 255)]
+#[test_case("16#FF_FF#", 0xFFFF)]
+#[test_case("2#1111_0000#", 240)]
+#[test_case("-16#80#", -128)]
+#[test_case("36#Zz#", 1295)]
+#[test_case("42", 42)]
+fn test_ada(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &ada()).unwrap(), x);
+}
+
+#[test_case("#xFF", 255)]
+#[test_case("#XFF", 255; "upper_x")]
+#[test_case("#o777", 511)]
+#[test_case("#O17", 15; "upper_o")]
+#[test_case("#b101", 5)]
+#[test_case("#B101", 5; "upper_b")]
+#[test_case("#36rZZ", 1295)]
+#[test_case("#36Rzz", 1295; "upper_r")]
+#[test_case("#3r210", 21)]
+#[test_case("#x-FF", -255)]
+#[test_case("#x+FF", 255)]
+#[test_case("#16r-80", -128)]
+#[test_case("42", 42)]
+#[test_case("-42", -42)]
+fn test_lisp(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &lisp()).unwrap(), x);
+}
+
+#[test_case("37#10", StrToIntError::InvalidRadix {radix: 37, position: 0})]
+#[test_case("+37#10", StrToIntError::InvalidRadix {radix: 37, position: 1})]
+#[test_case("1#0", StrToIntError::InvalidRadix {radix: 1, position: 0})]
+#[test_case("0#0", StrToIntError::InvalidRadix {radix: 0, position: 0})]
+#[test_case("99999999999#1", StrToIntError::InvalidRadix {radix: u32::MAX, position: 0})]
+#[test_case("16#", StrToIntError::NoDigits)]
+#[test_case("2#102", StrToIntError::InvalidCharacter {c: '2', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#FG", StrToIntError::InvalidCharacter {c: 'G', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#0x10", StrToIntError::InvalidCharacter {c: 'x', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#_F", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("16#FF#", StrToIntError::InvalidCharacter {c: '#', position: 5, reason: InvalidReason::TrailingCharacter})]
+#[test_case("#xFF", StrToIntError::InvalidCharacter {c: '#', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("16#100", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 5})]
+#[test_case("-16#1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+fn test_erlang_u8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<u8>(s, &erlang()).unwrap_err(), err);
+}
+
+#[test_case("16#FF", StrToIntError::InvalidCharacter {c: '#', position: 2, reason: InvalidReason::UnclosedHash})]
+#[test_case("-2#101", StrToIntError::InvalidCharacter {c: '#', position: 2, reason: InvalidReason::UnclosedHash})]
+#[test_case("16#F#F", StrToIntError::InvalidCharacter {c: '#', position: 4, reason: InvalidReason::TrailingCharacter})]
+#[test_case("16#0x1F", StrToIntError::InvalidCharacter {c: 'x', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#F_G", StrToIntError::InvalidCharacter {c: 'G', position: 5, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#_F#", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("16##", StrToIntError::NoDigits)]
+#[test_case("40#1#", StrToIntError::InvalidRadix {radix: 40, position: 0})]
+#[test_case("8#78#", StrToIntError::InvalidCharacter {c: '8', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("16#80#", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 4})]
+fn test_ada_i8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i8>(s, &ada()).unwrap_err(), err);
+}
+
+#[test_case("#37r10", StrToIntError::InvalidRadix {radix: 37, position: 1})]
+#[test_case("#1r0", StrToIntError::InvalidRadix {radix: 1, position: 1})]
+#[test_case("#x", StrToIntError::NoDigits)]
+#[test_case("#b102", StrToIntError::InvalidCharacter {c: '2', position: 4, reason: InvalidReason::InvalidDigit})]
+#[test_case("#r10", StrToIntError::InvalidCharacter {c: '#', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("#z10", StrToIntError::InvalidCharacter {c: '#', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("+#x1F", StrToIntError::InvalidCharacter {c: '#', position: 1, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("#x-1F", StrToIntError::InvalidCharacter {c: '-', position: 2, reason: InvalidReason::UnsignedMinus})]
+#[test_case("16#FF", StrToIntError::InvalidCharacter {c: '#', position: 2, reason: InvalidReason::TrailingCharacter})]
+fn test_lisp_u8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<u8>(s, &lisp()).unwrap_err(), err);
+}
+
+#[test_case("16#FF+1", 255, "+1")]
+#[test_case("36#zz zz", 1295, " zz")]
+#[test_case("7", 7, "")]
+fn test_erlang_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(
+        strtoint_prefix_with::<u32>(s, &erlang()).unwrap(),
+        (x, rest)
+    );
+}
+
+#[test_case("16#FF#, x", 255, ", x")]
+#[test_case("16#FF#FF", 255, "FF")]
+#[test_case("2#1_0#", 2, "")]
+fn test_ada_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(strtoint_prefix_with::<u32>(s, &ada()).unwrap(), (x, rest));
+}
+
+#[test]
+fn test_erlang_prefix_no_digits() {
+    assert_eq!(
+        strtoint_prefix_with::<u32>("16#", &erlang()),
+        Err(StrToIntError::NoDigits)
+    );
+}
+
+#[test]
+fn test_ada_prefix_unclosed() {
+    assert_eq!(
+        strtoint_prefix_with::<u32>("16#FF, x", &ada()),
+        Err(StrToIntError::InvalidCharacter {
+            c: '#',
+            position: 2,
+            reason: InvalidReason::UnclosedHash
+        })
+    );
+}
+
+#[test_case("#xFF)", 255, ")")]
+#[test_case("#36rZZ.", 1295, ".")]
+fn test_lisp_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(strtoint_prefix_with::<u32>(s, &lisp()).unwrap(), (x, rest));
+}
+
+#[test]
+fn test_hash_radix_bytes() {
+    assert_eq!(
+        strtoint_bytes_with::<u16>(b"16#BEEF", &erlang()),
+        Ok(0xBEEF)
+    );
+    assert_eq!(strtoint_bytes_with::<u16>(b"16#BEEF#", &ada()), Ok(0xBEEF));
+    assert_eq!(strtoint_bytes_with::<u16>(b"#xBEEF", &lisp()), Ok(0xBEEF));
+}
+
+#[test]
+fn test_hash_radix_ignores_default_radix() {
+    let opts = erlang().default_radix(16);
+    assert_eq!(strtoint_with::<u32>("10#99", &opts), Ok(99));
+    assert_eq!(strtoint_with::<u32>("99", &opts), Ok(0x99));
+}
+
+#[test]
+fn test_hash_radix_satisfies_required_prefix() {
+    let opts = erlang().radix_prefix(PrefixRule::Required);
+    assert_eq!(strtoint_with::<u32>("16#10", &opts), Ok(16));
+    assert_eq!(strtoint_with::<u32>("0x10", &opts), Ok(16));
+    assert_eq!(
+        strtoint_with::<u32>("10", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '1',
+            position: 0,
+            reason: InvalidReason::MissingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_hash_radix_with_radix_suffixes() {
+    let opts = erlang().radix_suffixes(true);
+    assert_eq!(strtoint_with::<u32>("16#FF", &opts), Ok(255));
+    assert_eq!(strtoint_with::<u32>("16#1b", &opts), Ok(0x1B));
+    assert_eq!(strtoint_with::<u32>("0FFh", &opts), Ok(255));
+}
+
+#[test]
+fn test_hash_radix_all_errors() {
+    let mut errors = ErrorArray::<4>::new();
+    assert!(strtoint_all_errors_with::<u32>("40#Z!_", &erlang(), &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [
+            StrToIntError::InvalidRadix {
+                radix: 40,
+                position: 0
+            },
+            StrToIntError::InvalidCharacter {
+                c: '!',
+                position: 4,
+                reason: InvalidReason::TrailingCharacter
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_ada_all_errors() {
+    let mut errors = ErrorArray::<4>::new();
+    assert!(strtoint_all_errors_with::<u32>("8#19", &ada(), &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [StrToIntError::InvalidCharacter {
+            c: '9',
+            position: 3,
+            reason: InvalidReason::InvalidDigit
+        },]
+    );
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+#![cfg(feature = "alloc")]
+use strtoint::{strtoint, InputError, InvalidReason, OutOfRangeKind, StrToIntError};
+
+#[test]
+fn test_display_with_label() {
+    let e = strtoint::<u32>("0x1G")
+        .unwrap_err()
+        .with_input("0x1G")
+        .with_label("max-connections");
+    assert_eq!(
+        e.to_string(),
+        "invalid value \"0x1G\" for max-connections: invalid character 'G' at position 3"
+    );
+}
+
+#[test]
+fn test_display_without_label() {
+    let e = InputError::new(
+        "300",
+        StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: 255,
+            position: 2,
+        },
+    );
+    assert_eq!(
+        e.to_string(),
+        "invalid value \"300\": value is too large for numeric type (maximum: 255)"
+    );
+}
+
+#[test]
+fn test_display_escapes_input() {
+    let e = strtoint::<u8>("1\n").unwrap_err().with_input("1\n");
+    assert_eq!(
+        e.to_string(),
+        "invalid value \"1\\n\": invalid character '\\n' at position 1"
+    );
+}
+
+#[test]
+fn test_accessors() {
+    let err = StrToIntError::InvalidCharacter {
+        c: '.',
+        position: 1,
+        reason: InvalidReason::TrailingCharacter,
+    };
+    let e = err.with_input(String::from("1.5")).with_label("PORT");
+    assert_eq!(e.input(), "1.5");
+    assert_eq!(e.label(), Some("PORT"));
+    assert_eq!(e.error(), err);
+    assert_eq!(
+        e.diagnostic().to_string(),
+        "invalid character '.' at position 1\n    1.5\n     ^ unexpected character after digits"
+    );
+    assert_eq!(StrToIntError::from(e), err);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_source() {
+    use std::error::Error;
+    let e = StrToIntError::NoDigits
+        .with_input("")
+        .with_label("--retries");
+    let source = e.source().unwrap();
+    assert_eq!(
+        source.downcast_ref::<StrToIntError>(),
+        Some(&StrToIntError::NoDigits)
+    );
+    assert!(source.source().is_none());
+}
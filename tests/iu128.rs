@@ -1,6 +1,6 @@
 #![cfg(test)]
 use core::num::{NonZeroI128, NonZeroU128};
-use strtoint::{strtoint, StrToIntError};
+use strtoint::{strtoint, InvalidReason, OutOfRangeKind, StrToIntError};
 use test_case::test_case;
 
 #[test_case("0", 0)]
@@ -15,8 +15,8 @@ fn test_strtoint_i128(s: &str, x: i128) {
     assert_eq!(strtoint::<i128>(s).unwrap(), x);
 }
 
-#[test_case("170141183460469231731687303715884105728", StrToIntError::OutOfRange)]
-#[test_case("-170141183460469231731687303715884105729", StrToIntError::OutOfRange)]
+#[test_case("170141183460469231731687303715884105728", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727, position: 38})]
+#[test_case("-170141183460469231731687303715884105729", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727, position: 39})]
 fn test_strtoint_i128_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<i128>(s).unwrap_err(), err);
 }
@@ -39,10 +39,10 @@ fn test_strtoint_u128(s: &str, x: u128) {
     assert_eq!(strtoint::<u128>(s).unwrap(), x);
 }
 
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-170141183460469231731687303715884105727", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-170141183460469231731687303715884105728", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("340282366920938463463374607431768211456", StrToIntError::OutOfRange)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-170141183460469231731687303715884105727", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-170141183460469231731687303715884105728", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("340282366920938463463374607431768211456", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 340282366920938463463374607431768211455, position: 38})]
 fn test_strtoint_u128_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<u128>(s).unwrap_err(), err);
 }
@@ -61,9 +61,9 @@ fn test_strtoint_nonzero_i128(s: &str, x: i128) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("170141183460469231731687303715884105728", StrToIntError::OutOfRange)]
-#[test_case("-170141183460469231731687303715884105729", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("170141183460469231731687303715884105728", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727, position: 38})]
+#[test_case("-170141183460469231731687303715884105729", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -170141183460469231731687303715884105728, max: 170141183460469231731687303715884105727, position: 39})]
 fn test_strtoint_nonzero_i128_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroI128>(s).unwrap_err(), err);
 }
@@ -88,11 +88,11 @@ fn test_strtoint_nonzero_u128(s: &str, x: u128) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-170141183460469231731687303715884105727", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-170141183460469231731687303715884105728", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("340282366920938463463374607431768211456", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-170141183460469231731687303715884105727", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-170141183460469231731687303715884105728", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("340282366920938463463374607431768211456", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 340282366920938463463374607431768211455, position: 38})]
 fn test_strtoint_nonzero_u128_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroU128>(s).unwrap_err(), err);
 }
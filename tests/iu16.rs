@@ -1,6 +1,6 @@
 #![cfg(test)]
 use core::num::{NonZeroI16, NonZeroU16};
-use strtoint::{strtoint, StrToIntError};
+use strtoint::{strtoint, InvalidReason, OutOfRangeKind, StrToIntError};
 use test_case::test_case;
 
 #[test_case("0", 0)]
@@ -12,8 +12,8 @@ fn test_strtoint_i16(s: &str, x: i16) {
     assert_eq!(strtoint::<i16>(s).unwrap(), x);
 }
 
-#[test_case("32768", StrToIntError::OutOfRange)]
-#[test_case("-32769", StrToIntError::OutOfRange)]
+#[test_case("32768", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -32768, max: 32767, position: 4})]
+#[test_case("-32769", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -32768, max: 32767, position: 5})]
 fn test_strtoint_i16_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<i16>(s).unwrap_err(), err);
 }
@@ -27,10 +27,10 @@ fn test_strtoint_u16(s: &str, x: u16) {
     assert_eq!(strtoint::<u16>(s).unwrap(), x);
 }
 
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("65536", StrToIntError::OutOfRange)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("65536", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 65535, position: 4})]
 fn test_strtoint_u16_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<u16>(s).unwrap_err(), err);
 }
@@ -46,9 +46,9 @@ fn test_strtoint_nonzero_i16(s: &str, x: i16) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("32768", StrToIntError::OutOfRange)]
-#[test_case("-32769", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("32768", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -32768, max: 32767, position: 4})]
+#[test_case("-32769", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -32768, max: 32767, position: 5})]
 fn test_strtoint_nonzero_i16_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroI16>(s).unwrap_err(), err);
 }
@@ -64,11 +64,11 @@ fn test_strtoint_nonzero_u16(s: &str, x: u16) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("65536", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("65536", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 65535, position: 4})]
 fn test_strtoint_nonzero_u16_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroU16>(s).unwrap_err(), err);
 }
@@ -1,6 +1,6 @@
 #![cfg(test)]
 use core::num::{NonZeroI64, NonZeroU64};
-use strtoint::{strtoint, StrToIntError};
+use strtoint::{strtoint, InvalidReason, OutOfRangeKind, StrToIntError};
 use test_case::test_case;
 
 #[test_case("0", 0; "zero")]
@@ -12,8 +12,8 @@ fn test_strtoint_i64(s: &str, x: i64) {
     assert_eq!(strtoint::<i64>(s).unwrap(), x);
 }
 
-#[test_case("9223372036854775808", StrToIntError::OutOfRange)]
-#[test_case("-9223372036854775809", StrToIntError::OutOfRange)]
+#[test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -9223372036854775808, max: 9223372036854775807, position: 18})]
+#[test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -9223372036854775808, max: 9223372036854775807, position: 19})]
 fn test_strtoint_i64_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<i64>(s).unwrap_err(), err);
 }
@@ -27,10 +27,10 @@ fn test_strtoint_u64(s: &str, x: u64) {
     assert_eq!(strtoint::<u64>(s).unwrap(), x);
 }
 
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("18446744073709551616", StrToIntError::OutOfRange)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 18446744073709551615, position: 19})]
 fn test_strtoint_u64_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<u64>(s).unwrap_err(), err);
 }
@@ -46,9 +46,9 @@ fn test_strtoint_nonzero_i64(s: &str, x: i64) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("9223372036854775808", StrToIntError::OutOfRange)]
-#[test_case("-9223372036854775809", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -9223372036854775808, max: 9223372036854775807, position: 18})]
+#[test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -9223372036854775808, max: 9223372036854775807, position: 19})]
 fn test_strtoint_nonzero_i64_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroI64>(s).unwrap_err(), err);
 }
@@ -64,11 +64,11 @@ fn test_strtoint_nonzero_u64(s: &str, x: u64) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("18446744073709551616", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 18446744073709551615, position: 19})]
 fn test_strtoint_nonzero_u64_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroU64>(s).unwrap_err(), err);
 }
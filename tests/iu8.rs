@@ -1,6 +1,6 @@
 #![cfg(test)]
 use core::num::{NonZeroI8, NonZeroU8};
-use strtoint::{strtoint, StrToIntError};
+use strtoint::{strtoint, InvalidReason, OutOfRangeKind, StrToIntError};
 use test_case::test_case;
 
 #[test_case("0", 0)]
@@ -12,8 +12,8 @@ fn test_strtoint_i8(s: &str, x: i8) {
     assert_eq!(strtoint::<i8>(s).unwrap(), x);
 }
 
-#[test_case("128", StrToIntError::OutOfRange)]
-#[test_case("-129", StrToIntError::OutOfRange)]
+#[test_case("128", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 2})]
+#[test_case("-129", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -128, max: 127, position: 3})]
 fn test_strtoint_i8_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<i8>(s).unwrap_err(), err);
 }
@@ -27,10 +27,11 @@ fn test_strtoint_u8(s: &str, x: u8) {
     assert_eq!(strtoint::<u8>(s).unwrap(), x);
 }
 
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-128", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-129", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("256", StrToIntError::OutOfRange)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-128", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-129", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("0x1_00", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 5})]
+#[test_case("256", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
 fn test_strtoint_u8_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<u8>(s).unwrap_err(), err);
 }
@@ -46,9 +47,9 @@ fn test_strtoint_nonzero_i8(s: &str, x: i8) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("128", StrToIntError::OutOfRange)]
-#[test_case("-129", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("128", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 2})]
+#[test_case("-129", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -128, max: 127, position: 3})]
 fn test_strtoint_nonzero_i8_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroI8>(s).unwrap_err(), err);
 }
@@ -64,11 +65,11 @@ fn test_strtoint_nonzero_u8(s: &str, x: u8) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-128", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-129", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("256", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-128", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-129", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("256", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
 fn test_strtoint_nonzero_u8_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroU8>(s).unwrap_err(), err);
 }
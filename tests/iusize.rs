@@ -1,8 +1,12 @@
 #![cfg(test)]
 use core::num::{NonZeroIsize, NonZeroUsize};
-use strtoint::{strtoint, StrToIntError};
+use strtoint::{strtoint, InvalidReason, OutOfRangeKind, StrToIntError};
 use test_case::test_case;
 
+const ISIZE_MIN: i128 = isize::MIN as i128;
+const ISIZE_MAX: u128 = isize::MAX as u128;
+const USIZE_MAX: u128 = usize::MAX as u128;
+
 #[test_case("0", 0)]
 #[test_case("1", 1)]
 #[test_case("-1", -1; "neg1")]
@@ -24,39 +28,63 @@ fn test_strtoint_isize(s: &str, x: isize) {
 
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("32768", StrToIntError::OutOfRange)
+    test_case("32768", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 4})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("-32769", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("2147483648", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("2147483648", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 9})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("-2147483649", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 6})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("-2147483649", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 4})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 9})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 18})
 )]
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("-32769", StrToIntError::OutOfRange)
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
 )]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("2147483648", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
 )]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("-2147483649", StrToIntError::OutOfRange)
+    target_pointer_width = "64",
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 19})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("9223372036854775808", StrToIntError::OutOfRange)
+    target_pointer_width = "16",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("-9223372036854775809", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
 )]
-#[test_case(
-    "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
-    StrToIntError::OutOfRange
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 20})
 )]
 fn test_strtoint_isize_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<isize>(s).unwrap_err(), err);
@@ -95,38 +123,54 @@ fn test_strtoint_usize(s: &str, x: usize) {
     assert_eq!(strtoint::<usize>(s).unwrap(), x);
 }
 
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0})]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("65536", StrToIntError::OutOfRange)
+    test_case("65536", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 4})
 )]
-#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483648", StrToIntError::InvalidCharacter {c: '-', position: 0}))]
-#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483649", StrToIntError::InvalidCharacter {c: '-', position: 0}))]
+#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483648", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}))]
+#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483649", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}))]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("4294967296", StrToIntError::OutOfRange)
+    target_pointer_width = "16",
+    test_case("4294967296", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("4294967296", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 9})
 )]
 #[cfg_attr(
     any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"),
-    test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0})
+    test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})
 )]
 #[cfg_attr(
     any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"),
-    test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0})
+    test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 5})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("18446744073709551616", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 10})
 )]
-#[test_case(
-    "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
-    StrToIntError::OutOfRange
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 19})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 7})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 12})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 22})
 )]
 fn test_strtoint_usize_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<usize>(s).unwrap_err(), err);
@@ -153,42 +197,66 @@ fn test_strtoint_nonzero_isize(s: &str, x: isize) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
+#[test_case("0", StrToIntError::Zero)]
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("32768", StrToIntError::OutOfRange)
+    test_case("32768", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 4})
 )]
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("-32769", StrToIntError::OutOfRange)
+    test_case("-32769", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
 )]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("2147483648", StrToIntError::OutOfRange)
+    target_pointer_width = "16",
+    test_case("2147483648", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("2147483648", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 9})
 )]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("-2147483649", StrToIntError::OutOfRange)
+    target_pointer_width = "16",
+    test_case("-2147483649", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 6})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("9223372036854775808", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("-2147483649", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("-9223372036854775809", StrToIntError::OutOfRange)
+    target_pointer_width = "16",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 4})
 )]
-#[test_case(
-    "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
-    StrToIntError::OutOfRange
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 9})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("9223372036854775808", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 18})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("-9223372036854775809", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: ISIZE_MIN, max: ISIZE_MAX, position: 19})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 5})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 10})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: ISIZE_MIN, max: ISIZE_MAX, position: 20})
 )]
 fn test_strtoint_nonzero_isize_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroIsize>(s).unwrap_err(), err);
@@ -229,39 +297,55 @@ fn test_strtoint_nonzero_usize(s: &str, x: usize) {
     );
 }
 
-#[test_case("0", StrToIntError::OutOfRange)]
-#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0})]
-#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0})]
+#[test_case("0", StrToIntError::Zero)]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32768", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("-32769", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("65536", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 4})
+)]
+#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483648", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}))]
+#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483649", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}))]
 #[cfg_attr(
     target_pointer_width = "16",
-    test_case("65536", StrToIntError::OutOfRange)
+    test_case("4294967296", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 5})
 )]
-#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483648", StrToIntError::InvalidCharacter {c: '-', position: 0}))]
-#[cfg_attr(any(target_pointer_width = "16", target_pointer_width = "32"), test_case("-2147483649", StrToIntError::InvalidCharacter {c: '-', position: 0}))]
 #[cfg_attr(
-    any(target_pointer_width = "16", target_pointer_width = "32"),
-    test_case("4294967296", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("4294967296", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 9})
 )]
 #[cfg_attr(
     any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"),
-    test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0})
+    test_case("-9223372036854775807", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})
 )]
 #[cfg_attr(
     any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"),
-    test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0})
+    test_case("-9223372036854775808", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 5})
 )]
 #[cfg_attr(
-    any(
-        target_pointer_width = "16",
-        target_pointer_width = "32",
-        target_pointer_width = "64"
-    ),
-    test_case("18446744073709551616", StrToIntError::OutOfRange)
+    target_pointer_width = "32",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 10})
 )]
-#[test_case(
-    "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
-    StrToIntError::OutOfRange
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("18446744073709551616", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 19})
+)]
+#[cfg_attr(
+    target_pointer_width = "16",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 7})
+)]
+#[cfg_attr(
+    target_pointer_width = "32",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 12})
+)]
+#[cfg_attr(
+    target_pointer_width = "64",
+    test_case("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: USIZE_MAX, position: 22})
 )]
 fn test_strtoint_nonzero_usize_err(s: &str, err: StrToIntError) {
     assert_eq!(strtoint::<NonZeroUsize>(s).unwrap_err(), err);
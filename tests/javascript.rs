@@ -0,0 +1,137 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_prefix_with, strtoint_with, InvalidReason, OutOfRangeKind, ParseOptions, StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case("0", 0)]
+#[test_case("-0", 0)]
+#[test_case("+17", 17)]
+#[test_case("-17", -17)]
+#[test_case("0x1F", 31)]
+#[test_case("0X1f", 31; "upper_x")]
+#[test_case("+0x1F", 31)]
+#[test_case("-0x1F", -31)]
+#[test_case("0x0001", 1)]
+fn test_json5(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::json5()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("0x", StrToIntError::NoDigits)]
+#[test_case("00", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("0644", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("-01", StrToIntError::InvalidCharacter {c: '0', position: 1, reason: InvalidReason::LeadingZero})]
+#[test_case("0o17", StrToIntError::InvalidCharacter {c: 'o', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0b1", StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("1_000", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("0x_1", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1n", StrToIntError::InvalidCharacter {c: 'n', position: 1, reason: InvalidReason::InvalidDigit})]
+fn test_json5_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::json5()), Err(err));
+}
+
+// Expected values were checked against Node.js.
+#[test_case("0", 0)]
+#[test_case("0n", 0)]
+#[test_case("-0", 0)]
+#[test_case("123", 123)]
+#[test_case("123n", 123)]
+#[test_case("-123n", -123)]
+#[test_case("123_456n", 123_456)]
+#[test_case("1_000", 1000)]
+#[test_case("0x1fn", 31)]
+#[test_case("0X1F", 31)]
+#[test_case("0x1_Fn", 31)]
+#[test_case("0o17", 15)]
+#[test_case("0O17n", 15)]
+#[test_case("0b101", 5)]
+#[test_case("0B1n", 1)]
+#[test_case("-0b1_01n", -5)]
+fn test_javascript(s: &str, x: i64) {
+    assert_eq!(strtoint_with::<i64>(s, &ParseOptions::javascript()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("n", StrToIntError::InvalidCharacter {c: 'n', position: 0, reason: InvalidReason::InvalidDigit})]
+#[test_case("0xn", StrToIntError::InvalidCharacter {c: 'n', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("1nn", StrToIntError::InvalidCharacter {c: 'n', position: 2, reason: InvalidReason::InvalidSuffix})]
+#[test_case("1n1", StrToIntError::InvalidCharacter {c: '1', position: 2, reason: InvalidReason::TrailingCharacter})]
+#[test_case("1__0", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1_", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1_n", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("0x1F_n", StrToIntError::InvalidCharacter {c: '_', position: 4, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("0x_1", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("0_1", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("0_0", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("00", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("0777", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("01n", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("08", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+fn test_javascript_err(s: &str, err: StrToIntError) {
+    assert_eq!(
+        strtoint_with::<i64>(s, &ParseOptions::javascript()),
+        Err(err)
+    );
+}
+
+#[test_case("0777", Ok(511))]
+#[test_case("00", Ok(0))]
+#[test_case("0", Ok(0))]
+#[test_case("0n", Ok(0))]
+#[test_case("0x1Fn", Ok(31))]
+#[test_case("123n", Ok(123))]
+#[test_case("0777n", Err(StrToIntError::InvalidCharacter {c: 'n', position: 4, reason: InvalidReason::DisallowedBigIntSuffix}))]
+#[test_case("089n", Err(StrToIntError::InvalidCharacter {c: 'n', position: 3, reason: InvalidReason::DisallowedBigIntSuffix}))]
+#[test_case("08", Ok(8))]
+#[test_case("089", Ok(89))]
+#[test_case("0789", Ok(789))]
+#[test_case("0709", Ok(709))]
+#[test_case("0_7", Err(StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore}))]
+#[test_case("07_7", Err(StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore}))]
+#[test_case("08_9", Err(StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore}))]
+#[test_case("0x1_F", Ok(31))]
+#[test_case("1_0", Ok(10))]
+fn test_javascript_legacy_octal(s: &str, r: Result<u32, StrToIntError>) {
+    let opts = ParseOptions::javascript().leading_zero_octal(true);
+    assert_eq!(strtoint_with::<u32>(s, &opts), r);
+}
+
+#[test]
+fn test_bigint_out_of_range() {
+    let opts = ParseOptions::javascript();
+    assert_eq!(strtoint_with::<u8>("255n", &opts), Ok(255));
+    assert_eq!(
+        strtoint_with::<u8>("256n", &opts),
+        Err(StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: 255,
+            position: 2
+        })
+    );
+}
+
+#[test_case("123n + 1", 123, " + 1")]
+#[test_case("0x1fn)", 31, ")")]
+#[test_case("7nn", 7, "n")]
+fn test_bigint_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(
+        strtoint_prefix_with::<u32>(s, &ParseOptions::javascript()),
+        Ok((x, rest))
+    );
+}
+
+#[test]
+fn test_bigint_suffix_radix_36() {
+    let opts = ParseOptions::new().bigint_suffix(true).default_radix(36);
+    assert_eq!(strtoint_with::<u32>("1n", &opts), Ok(59));
+}
+
+#[test]
+fn test_bigint_suffix_with_type_suffixes() {
+    let opts = ParseOptions::new().bigint_suffix(true).type_suffixes(true);
+    assert_eq!(strtoint_with::<u32>("5n", &opts), Ok(5));
+    assert_eq!(strtoint_with::<u32>("5u32", &opts), Ok(5));
+}
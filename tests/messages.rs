@@ -0,0 +1,169 @@
+#![cfg(test)]
+use core::fmt;
+use strtoint::{
+    strtoint, Diagnostic, ErrorMessages, IntSuffix, InvalidReason, OutOfRangeKind, StrToIntError,
+};
+use test_case::test_case;
+
+struct German;
+
+impl ErrorMessages for German {
+    fn no_digits(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "keine Ziffern in der Eingabe")
+    }
+
+    fn invalid_character(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        c: char,
+        position: usize,
+        reason: InvalidReason,
+    ) -> fmt::Result {
+        let grund = match reason {
+            InvalidReason::InvalidDigit => "keine gültige Ziffer",
+            _ => "unerwartet",
+        };
+        write!(
+            f,
+            "ungültiges Zeichen {c:?} an Position {position} ({grund})"
+        )
+    }
+
+    fn out_of_range(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        kind: OutOfRangeKind,
+        min: i128,
+        max: u128,
+        position: usize,
+    ) -> fmt::Result {
+        match kind {
+            OutOfRangeKind::TooLarge => write!(f, "Wert größer als {max} (Position {position})"),
+            OutOfRangeKind::TooSmall => write!(f, "Wert kleiner als {min} (Position {position})"),
+        }
+    }
+
+    fn suffix_mismatch(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        suffix: IntSuffix,
+        position: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "falsches Suffix {} an Position {position}",
+            suffix.as_str()
+        )
+    }
+
+    fn zero(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Wert ist null")
+    }
+
+    fn invalid_reason(&self, f: &mut fmt::Formatter<'_>, reason: InvalidReason) -> fmt::Result {
+        match reason {
+            InvalidReason::InvalidDigit => write!(f, "keine gültige Ziffer"),
+            _ => write!(f, "unerwartet"),
+        }
+    }
+
+    fn diagnostic_label(&self, f: &mut fmt::Formatter<'_>, error: StrToIntError) -> fmt::Result {
+        match error {
+            StrToIntError::InvalidCharacter { reason, .. } => self.invalid_reason(f, reason),
+            StrToIntError::OutOfRange { .. } => write!(f, "Wert wird hier zu groß"),
+            _ => write!(f, "hier"),
+        }
+    }
+}
+
+struct Untranslated;
+
+impl ErrorMessages for Untranslated {}
+
+#[test_case(StrToIntError::NoDigits, "keine Ziffern in der Eingabe")]
+#[test_case(
+    StrToIntError::InvalidCharacter {c: 'g', position: 3, reason: InvalidReason::InvalidDigit},
+    "ungültiges Zeichen 'g' an Position 3 (keine gültige Ziffer)"
+)]
+#[test_case(
+    StrToIntError::InvalidCharacter {c: ' ', position: 0, reason: InvalidReason::UnexpectedCharacter},
+    "ungültiges Zeichen ' ' an Position 0 (unerwartet)"
+)]
+#[test_case(
+    StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 2},
+    "Wert größer als 127 (Position 2)"
+)]
+#[test_case(
+    StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -128, max: 127, position: 3},
+    "Wert kleiner als -128 (Position 3)"
+)]
+#[test_case(
+    StrToIntError::SuffixMismatch {suffix: IntSuffix::U16, position: 1},
+    "falsches Suffix u16 an Position 1"
+)]
+#[test_case(StrToIntError::Zero, "Wert ist null")]
+fn test_localized(err: StrToIntError, msg: &str) {
+    assert_eq!(err.localized(&German).to_string(), msg);
+}
+
+#[test_case(StrToIntError::NoDigits)]
+#[test_case(StrToIntError::InvalidCharacter {c: 'g', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case(StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 2})]
+#[test_case(StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -128, max: 127, position: 3})]
+#[test_case(StrToIntError::SuffixMismatch {suffix: IntSuffix::U16, position: 1})]
+#[test_case(StrToIntError::InvalidRadix {radix: 37, position: 0})]
+#[test_case(StrToIntError::ExceedsWidth {width: 4, position: 4})]
+#[test_case(StrToIntError::UnknownDigit {c: 'z', position: 3})]
+#[test_case(StrToIntError::Zero)]
+fn test_localized_default(err: StrToIntError) {
+    assert_eq!(err.localized(&Untranslated).to_string(), err.to_string());
+}
+
+#[test]
+fn test_localized_dyn() {
+    let providers: [&dyn ErrorMessages; 2] = [&German, &Untranslated];
+    let msgs = providers.map(|m| StrToIntError::Zero.localized(m).to_string());
+    assert_eq!(
+        msgs,
+        [
+            "Wert ist null",
+            "value is zero, but numeric type is nonzero"
+        ]
+    );
+}
+
+#[test_case("0x1G", concat!(
+    "ungültiges Zeichen 'G' an Position 3 (keine gültige Ziffer)\n",
+    "    0x1G\n",
+    "       ^ keine gültige Ziffer",
+))]
+#[test_case("300", concat!(
+    "Wert größer als 255 (Position 2)\n",
+    "    300\n",
+    "      ^ Wert wird hier zu groß",
+))]
+#[test_case("", concat!(
+    "keine Ziffern in der Eingabe\n",
+    "    \n",
+    "    ^ hier",
+))]
+fn test_localized_diagnostic(s: &str, rendered: &str) {
+    let e = strtoint::<u8>(s).unwrap_err();
+    assert_eq!(
+        Diagnostic::new(s, e).localized(&German).to_string(),
+        rendered
+    );
+}
+
+#[test_case("0x1G")]
+#[test_case("300")]
+#[test_case("")]
+#[test_case("1u16")]
+fn test_localized_diagnostic_default(s: &str) {
+    let e = strtoint::<u8>(s).unwrap_err();
+    let diagnostic = Diagnostic::new(s, e);
+    assert_eq!(
+        diagnostic.localized(&Untranslated).to_string(),
+        diagnostic.to_string()
+    );
+}
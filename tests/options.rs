@@ -0,0 +1,177 @@
+#![cfg(test)]
+use core::num::NonZeroU16;
+use strtoint::{
+    strtoint, strtoint_with, InvalidReason, OutOfRangeKind, ParseOptions, StrToInt, StrToIntError,
+    Underscores,
+};
+use test_case::test_case;
+
+#[test_case("0", 0)]
+#[test_case("-42", -42)]
+#[test_case("+42", 42; "pos42")]
+#[test_case("0x2A", 42)]
+#[test_case("0o52", 42)]
+#[test_case("0b101010", 42)]
+#[test_case("0x___2A___", 42)]
+#[test_case("4_2", 42)]
+fn test_default_options_match_strtoint(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::new()).unwrap(), x);
+    assert_eq!(
+        strtoint_with::<i32>(s, &ParseOptions::default()).unwrap(),
+        x
+    );
+    assert_eq!(strtoint::<i32>(s).unwrap(), x);
+}
+
+#[test]
+fn test_trait_method() {
+    let opts = ParseOptions::new().hex_prefix(false);
+    assert_eq!(i64::strtoint_with("0o777", &opts).unwrap(), 511);
+    assert_eq!(
+        i64::strtoint_with("0x777", &opts).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 1,
+            reason: InvalidReason::InvalidDigit
+        }
+    );
+}
+
+#[test]
+fn test_no_plus_sign() {
+    let opts = ParseOptions::new().plus_sign(false);
+    assert_eq!(strtoint_with::<i32>("-12", &opts).unwrap(), -12);
+    assert_eq!(
+        strtoint_with::<i32>("+12", &opts).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: '+',
+            position: 0,
+            reason: InvalidReason::DisallowedSign
+        }
+    );
+}
+
+#[test]
+fn test_no_plus_sign_after_whitespace() {
+    let opts = ParseOptions::new()
+        .plus_sign(false)
+        .leading_whitespace(true);
+    assert_eq!(
+        strtoint_with::<i32>("  +12", &opts).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: '+',
+            position: 2,
+            reason: InvalidReason::DisallowedSign
+        }
+    );
+    assert_eq!(
+        strtoint_with::<i32>(" -+12", &opts).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: '+',
+            position: 2,
+            reason: InvalidReason::MisplacedSign
+        }
+    );
+}
+
+#[test_case("-0", 0; "neg0")]
+#[test_case("-0x0", 0; "neg_hex_0")]
+#[test_case("-0_0", 0; "neg0gap0")]
+#[test_case("0", 0)]
+#[test_case("12", 12)]
+fn test_unsigned_minus(s: &str, x: u32) {
+    let opts = ParseOptions::new().unsigned_minus(true);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap(), x);
+}
+
+#[test_case("-1", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: 0, max: 4294967295, position: 1})]
+#[test_case("-0x1", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: 0, max: 4294967295, position: 3})]
+#[test_case("-", StrToIntError::NoDigits)]
+#[test_case("-+1", StrToIntError::InvalidCharacter { c: '+', position: 1, reason: InvalidReason::MisplacedSign })]
+fn test_unsigned_minus_err(s: &str, err: StrToIntError) {
+    let opts = ParseOptions::new().unsigned_minus(true);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap_err(), err);
+}
+
+#[test]
+fn test_unsigned_minus_nonzero() {
+    let opts = ParseOptions::new().unsigned_minus(true);
+    assert_eq!(
+        strtoint_with::<NonZeroU16>("-0", &opts).unwrap_err(),
+        StrToIntError::Zero
+    );
+    assert_eq!(
+        strtoint_with::<NonZeroU16>("-1", &opts).unwrap_err(),
+        StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooSmall,
+            min: 0,
+            max: 65535,
+            position: 1
+        }
+    );
+}
+
+#[test_case(
+    ParseOptions::new().hex_prefix(false),
+    "0x10",
+    StrToIntError::InvalidCharacter {
+        c: 'x',
+        position: 1,
+        reason: InvalidReason::InvalidDigit
+    }
+)]
+#[test_case(
+    ParseOptions::new().octal_prefix(false),
+    "0o10",
+    StrToIntError::InvalidCharacter {
+        c: 'o',
+        position: 1,
+        reason: InvalidReason::InvalidDigit
+    }
+)]
+#[test_case(
+    ParseOptions::new().binary_prefix(false),
+    "-0b10",
+    StrToIntError::InvalidCharacter {
+        c: 'b',
+        position: 2,
+        reason: InvalidReason::InvalidDigit
+    }
+)]
+fn test_disabled_prefix(opts: ParseOptions, s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i32>(s, &opts).unwrap_err(), err);
+}
+
+#[test]
+fn test_disabled_prefix_leaves_others() {
+    let opts = ParseOptions::new().hex_prefix(false).octal_prefix(false);
+    assert_eq!(strtoint_with::<u8>("0b11", &opts).unwrap(), 3);
+    assert_eq!(strtoint_with::<u8>("011", &opts).unwrap(), 11);
+}
+
+#[test_case("1_000", StrToIntError::InvalidCharacter { c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore })]
+#[test_case("0x_1", StrToIntError::InvalidCharacter { c: '_', position: 2, reason: InvalidReason::LeadingUnderscore })]
+#[test_case("_1", StrToIntError::InvalidCharacter { c: '_', position: 0, reason: InvalidReason::LeadingUnderscore })]
+fn test_underscores_forbidden(s: &str, err: StrToIntError) {
+    let opts = ParseOptions::new().underscores(Underscores::Forbidden);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap_err(), err);
+}
+
+#[test_case("1_000", 1000)]
+#[test_case("0x1_F", 31)]
+#[test_case("1_2_3", 123)]
+fn test_underscores_between_digits(s: &str, x: u32) {
+    let opts = ParseOptions::new().underscores(Underscores::BetweenDigits);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap(), x);
+}
+
+#[test_case("_1", StrToIntError::InvalidCharacter { c: '_', position: 0, reason: InvalidReason::LeadingUnderscore })]
+#[test_case("0x_1", StrToIntError::InvalidCharacter { c: '_', position: 2, reason: InvalidReason::LeadingUnderscore })]
+#[test_case("1__0", StrToIntError::InvalidCharacter { c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore })]
+#[test_case("10_", StrToIntError::InvalidCharacter { c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore })]
+#[test_case("0x_", StrToIntError::InvalidCharacter { c: '_', position: 2, reason: InvalidReason::LeadingUnderscore })]
+#[test_case("1_x", StrToIntError::InvalidCharacter { c: 'x', position: 2, reason: InvalidReason::InvalidDigit })]
+fn test_underscores_between_digits_err(s: &str, err: StrToIntError) {
+    let opts = ParseOptions::new().underscores(Underscores::BetweenDigits);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap_err(), err);
+}
@@ -0,0 +1,258 @@
+#![cfg(test)]
+use core::num::{NonZeroI8, NonZeroU16, NonZeroU8};
+use strtoint::{
+    strtoint_overflowing_with, strtoint_prefix_overflowing_with, strtoint_prefix_with,
+    strtoint_with, InvalidReason, OutOfRangeKind, Overflow, ParseOptions, StrToInt, StrToIntError,
+};
+use test_case::test_case;
+
+const SATURATE: ParseOptions = ParseOptions::new().overflow(Overflow::Saturate);
+const WRAP: ParseOptions = ParseOptions::new().overflow(Overflow::Wrap);
+
+#[test_case("0", 0, false)]
+#[test_case("127", 127, false)]
+#[test_case("128", 127, true)]
+#[test_case("-128", -128, false)]
+#[test_case("-129", -128, true)]
+#[test_case("99999999999999999999999999999999999999999999", 127, true)]
+#[test_case("-0xffffffffffffffffffffffffffffffffffffffff", -128, true)]
+fn test_saturate_i8(s: &str, x: i8, overflowed: bool) {
+    assert_eq!(
+        strtoint_overflowing_with::<i8>(s, &SATURATE).unwrap(),
+        (x, overflowed)
+    );
+}
+
+#[test_case("65536", u16::MAX)]
+#[test_case("0x1_0000_0000", u16::MAX)]
+fn test_saturate_u16(s: &str, x: u16) {
+    assert_eq!(strtoint_with::<u16>(s, &SATURATE).unwrap(), x);
+}
+
+#[test_case("2147483648", i32::MAX)]
+#[test_case("-2147483649", i32::MIN)]
+fn test_saturate_i32(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &SATURATE).unwrap(), x);
+}
+
+#[test_case("18446744073709551616", u64::MAX)]
+#[test_case(
+    "0b1_0000000000000000000000000000000000000000000000000000000000000000",
+    u64::MAX
+)]
+fn test_saturate_u64(s: &str, x: u64) {
+    assert_eq!(strtoint_with::<u64>(s, &SATURATE).unwrap(), x);
+}
+
+#[test_case("170141183460469231731687303715884105728", i128::MAX)]
+#[test_case("-170141183460469231731687303715884105729", i128::MIN)]
+#[test_case("-9999999999999999999999999999999999999999999999", i128::MIN)]
+fn test_saturate_i128(s: &str, x: i128) {
+    assert_eq!(strtoint_with::<i128>(s, &SATURATE).unwrap(), x);
+}
+
+#[test_case("340282366920938463463374607431768211456", u128::MAX)]
+#[test_case("0x1_0000_0000_0000_0000_0000_0000_0000_0000", u128::MAX)]
+fn test_saturate_u128(s: &str, x: u128) {
+    assert_eq!(strtoint_with::<u128>(s, &SATURATE).unwrap(), x);
+}
+
+#[test]
+fn test_saturate_usize_isize() {
+    assert_eq!(
+        strtoint_with::<usize>("0x1_0000_0000_0000_0000_0000", &SATURATE).unwrap(),
+        usize::MAX
+    );
+    assert_eq!(
+        strtoint_with::<isize>("-0x1_0000_0000_0000_0000_0000", &SATURATE).unwrap(),
+        isize::MIN
+    );
+}
+
+#[test_case("-0", 0, false; "neg0")]
+#[test_case("-1", 0, true; "neg1")]
+#[test_case("-500", 0, true; "neg500")]
+#[test_case("500", 255, true)]
+fn test_saturate_unsigned_minus(s: &str, x: u8, overflowed: bool) {
+    let opts = SATURATE.unsigned_minus(true);
+    assert_eq!(
+        strtoint_overflowing_with::<u8>(s, &opts).unwrap(),
+        (x, overflowed)
+    );
+}
+
+#[test_case("127", 127, false)]
+#[test_case("128", -128, true)]
+#[test_case("255", -1, true)]
+#[test_case("256", 0, true)]
+#[test_case("-129", 127, true)]
+#[test_case("0x1234", 0x34, true)]
+fn test_wrap_i8(s: &str, x: i8, overflowed: bool) {
+    assert_eq!(
+        strtoint_overflowing_with::<i8>(s, &WRAP).unwrap(),
+        (x, overflowed)
+    );
+}
+
+#[test_case("65536", 0)]
+#[test_case("65537", 1)]
+#[test_case("0xABCD_1234", 0x1234)]
+fn test_wrap_u16(s: &str, x: u16) {
+    assert_eq!(strtoint_with::<u16>(s, &WRAP).unwrap(), x);
+}
+
+#[test_case("4294967296", 0)]
+#[test_case("-2147483649", 2147483647)]
+fn test_wrap_i32(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &WRAP).unwrap(), x);
+}
+
+#[test_case("0x1_2345_6789_ABCD_EF01_2345", 0x6789_ABCD_EF01_2345)]
+#[test_case("18446744073709551617", 1)]
+fn test_wrap_u64(s: &str, x: u64) {
+    assert_eq!(strtoint_with::<u64>(s, &WRAP).unwrap(), x);
+}
+
+#[test_case("0x1_0000_0000_0000_0000_0000_0000_0000_0005", 5)]
+#[test_case("340282366920938463463374607431768211457", 1)]
+fn test_wrap_u128(s: &str, x: u128) {
+    assert_eq!(strtoint_with::<u128>(s, &WRAP).unwrap(), x);
+}
+
+#[test_case("170141183460469231731687303715884105728", i128::MIN)]
+#[test_case("-170141183460469231731687303715884105729", i128::MAX)]
+fn test_wrap_i128(s: &str, x: i128) {
+    assert_eq!(strtoint_with::<i128>(s, &WRAP).unwrap(), x);
+}
+
+#[test]
+fn test_wrap_usize_isize() {
+    assert_eq!(
+        strtoint_with::<usize>("0x1_0000_0000_0000_0000_0000_0000_0000_0007", &WRAP).unwrap(),
+        7
+    );
+    assert_eq!(
+        strtoint_with::<isize>("-0x1_0000_0000_0000_0000_0000_0000_0000_0001", &WRAP).unwrap(),
+        -1
+    );
+}
+
+#[test_case("-1", 255)]
+#[test_case("-255", 1)]
+#[test_case("-256", 0)]
+fn test_wrap_unsigned_minus(s: &str, x: u8) {
+    let opts = WRAP.unsigned_minus(true);
+    assert_eq!(
+        strtoint_overflowing_with::<u8>(s, &opts).unwrap(),
+        (x, true)
+    );
+}
+
+#[test]
+fn test_error_mode_never_overflows() {
+    let opts = ParseOptions::new();
+    assert_eq!(
+        strtoint_overflowing_with::<u8>("255", &opts).unwrap(),
+        (255, false)
+    );
+    assert_eq!(
+        strtoint_overflowing_with::<u8>("256", &opts).unwrap_err(),
+        StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: 255,
+            position: 2
+        }
+    );
+}
+
+#[test]
+fn test_overflow_still_validates_digits() {
+    assert_eq!(
+        strtoint_with::<u8>("9999x", &SATURATE).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 4,
+            reason: InvalidReason::InvalidDigit
+        }
+    );
+    assert_eq!(
+        strtoint_prefix_with::<u8>("9999x", &WRAP).unwrap(),
+        (15, "x")
+    );
+}
+
+#[test]
+fn test_saturate_nonzero() {
+    assert_eq!(
+        strtoint_overflowing_with::<NonZeroU8>("1000", &SATURATE).unwrap(),
+        (NonZeroU8::MAX, true)
+    );
+    assert_eq!(
+        strtoint_overflowing_with::<NonZeroI8>("-1000", &SATURATE).unwrap(),
+        (NonZeroI8::MIN, true)
+    );
+    assert_eq!(
+        strtoint_overflowing_with::<NonZeroU8>("-5", &SATURATE.unsigned_minus(true)).unwrap(),
+        (NonZeroU8::MIN, true)
+    );
+    assert_eq!(
+        strtoint_with::<NonZeroU8>("0", &SATURATE).unwrap_err(),
+        StrToIntError::Zero
+    );
+    assert_eq!(
+        strtoint_with::<NonZeroU8>("-0", &SATURATE.unsigned_minus(true)).unwrap_err(),
+        StrToIntError::Zero
+    );
+}
+
+/// A type that implements only the required methods of `StrToInt`
+#[derive(Debug, Eq, PartialEq)]
+struct Custom(u8);
+
+impl StrToInt for Custom {
+    type Err = StrToIntError;
+
+    fn strtoint_with(s: &str, options: &ParseOptions) -> Result<Custom, StrToIntError> {
+        u8::strtoint_with(s, options).map(Custom)
+    }
+
+    fn strtoint_bytes_with(s: &[u8], options: &ParseOptions) -> Result<Custom, StrToIntError> {
+        u8::strtoint_bytes_with(s, options).map(Custom)
+    }
+
+    fn strtoint_prefix_with(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Custom, usize), StrToIntError> {
+        u8::strtoint_prefix_with(s, options).map(|(x, len)| (Custom(x), len))
+    }
+}
+
+#[test]
+fn test_overflowing_default_impl() {
+    assert_eq!(
+        strtoint_overflowing_with::<Custom>("300", &SATURATE),
+        Ok((Custom(255), false))
+    );
+    assert_eq!(
+        strtoint_overflowing_with::<Custom>("12", &SATURATE),
+        Ok((Custom(12), false))
+    );
+    assert_eq!(
+        strtoint_prefix_overflowing_with::<Custom>("300x", &SATURATE),
+        Ok((Custom(255), "x", false))
+    );
+}
+
+#[test]
+fn test_wrap_nonzero() {
+    assert_eq!(
+        strtoint_overflowing_with::<NonZeroU16>("65537", &WRAP).unwrap(),
+        (NonZeroU16::new(1).unwrap(), true)
+    );
+    assert_eq!(
+        strtoint_with::<NonZeroU16>("65536", &WRAP).unwrap_err(),
+        StrToIntError::Zero
+    );
+}
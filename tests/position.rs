@@ -0,0 +1,110 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint, strtoint_with, InvalidReason, LineColumn, OutOfRangeKind, ParseOptions, PositionUnit,
+    StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case(PositionUnit::Bytes, "añ😀x", 7, Some(7))]
+#[test_case(PositionUnit::Chars, "añ😀x", 7, Some(3))]
+#[test_case(PositionUnit::Utf16, "añ😀x", 7, Some(4))]
+#[test_case(PositionUnit::Bytes, "añ😀x", 8, Some(8))]
+#[test_case(PositionUnit::Chars, "añ😀x", 8, Some(4))]
+#[test_case(PositionUnit::Utf16, "añ😀x", 8, Some(5))]
+#[test_case(PositionUnit::Bytes, "añ😀x", 0, Some(0))]
+#[test_case(PositionUnit::Chars, "añ😀x", 2, None)]
+#[test_case(PositionUnit::Bytes, "añ😀x", 9, None)]
+fn test_convert(unit: PositionUnit, s: &str, position: usize, expected: Option<usize>) {
+    assert_eq!(unit.convert(s, position), expected);
+}
+
+#[test_case("42", 0, PositionUnit::Bytes, Some(LineColumn {line: 1, column: 1}))]
+#[test_case("42", 2, PositionUnit::Bytes, Some(LineColumn {line: 1, column: 3}))]
+#[test_case("ä\nö\n😀x", 6, PositionUnit::Bytes, Some(LineColumn {line: 3, column: 1}))]
+#[test_case("ä\nö\n😀x", 10, PositionUnit::Bytes, Some(LineColumn {line: 3, column: 5}))]
+#[test_case("ä\nö\n😀x", 10, PositionUnit::Chars, Some(LineColumn {line: 3, column: 2}))]
+#[test_case("ä\nö\n😀x", 10, PositionUnit::Utf16, Some(LineColumn {line: 3, column: 3}))]
+#[test_case("ä\nö\n", 6, PositionUnit::Chars, Some(LineColumn {line: 3, column: 1}))]
+#[test_case("ä\nö\n", 1, PositionUnit::Chars, None)]
+#[test_case("ä\nö\n", 7, PositionUnit::Chars, None)]
+fn test_line_column(s: &str, position: usize, unit: PositionUnit, expected: Option<LineColumn>) {
+    assert_eq!(LineColumn::new(s, position, unit), expected);
+}
+
+#[test]
+fn test_display_line_column() {
+    assert_eq!(
+        LineColumn {
+            line: 3,
+            column: 14
+        }
+        .to_string(),
+        "3:14"
+    );
+}
+
+#[test_case(StrToIntError::NoDigits, None)]
+#[test_case(StrToIntError::Zero, None)]
+#[test_case(StrToIntError::InvalidCharacter {c: 'x', position: 3, reason: InvalidReason::InvalidDigit}, Some(3))]
+#[test_case(StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2}, Some(2))]
+fn test_position(err: StrToIntError, position: Option<usize>) {
+    assert_eq!(err.position(), position);
+}
+
+#[test]
+fn test_with_offset() {
+    assert_eq!(
+        StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 3,
+            reason: InvalidReason::InvalidDigit
+        }
+        .with_offset(10),
+        StrToIntError::InvalidCharacter {
+            c: 'x',
+            position: 13,
+            reason: InvalidReason::InvalidDigit
+        }
+    );
+    assert_eq!(
+        StrToIntError::NoDigits.with_offset(10),
+        StrToIntError::NoDigits
+    );
+}
+
+#[test]
+fn test_error_in_document() {
+    let doc = "# «config»\nlimit = 😀 1_0O0\n";
+    let start = doc.find("1_0O0").unwrap();
+    let e = strtoint::<u32>(&doc[start..])
+        .unwrap_err()
+        .with_offset(start);
+    assert_eq!(e.position(), Some(start + 3));
+    assert_eq!(e.position_in(doc, PositionUnit::Bytes), Some(29));
+    assert_eq!(e.position_in(doc, PositionUnit::Chars), Some(24));
+    assert_eq!(e.position_in(doc, PositionUnit::Utf16), Some(25));
+    assert_eq!(
+        e.line_column(doc, PositionUnit::Utf16),
+        Some(LineColumn {
+            line: 2,
+            column: 15
+        })
+    );
+    assert_eq!(e.position_in("1_0", PositionUnit::Chars), None);
+}
+
+#[test]
+fn test_unicode_digits_position() {
+    let s = "١٢x";
+    let e = strtoint_with::<u32>(s, &ParseOptions::python()).unwrap_err();
+    assert_eq!(e.position_in(s, PositionUnit::Bytes), Some(4));
+    assert_eq!(e.position_in(s, PositionUnit::Chars), Some(2));
+    assert_eq!(e.position_in(s, PositionUnit::Utf16), Some(2));
+}
+
+#[test]
+fn test_no_position() {
+    let e = strtoint::<u32>("").unwrap_err();
+    assert_eq!(e.position_in("", PositionUnit::Chars), None);
+    assert_eq!(e.line_column("", PositionUnit::Chars), None);
+}
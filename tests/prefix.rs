@@ -0,0 +1,108 @@
+#![cfg(test)]
+use core::num::NonZeroU32;
+use strtoint::{
+    strtoint_advance, strtoint_advance_with, strtoint_prefix, strtoint_prefix_with, InvalidReason,
+    OutOfRangeKind, ParseOptions, StrToInt, StrToIntError, Underscores,
+};
+use test_case::test_case;
+
+#[test_case("0", 0, "")]
+#[test_case("42", 42, "")]
+#[test_case("42px", 42, "px")]
+#[test_case("-42px", -42, "px"; "neg42px")]
+#[test_case("+42 ", 42, " "; "pos42space")]
+#[test_case("0x1F,rest", 31, ",rest")]
+#[test_case("0x1Fg", 31, "g")]
+#[test_case("0o17", 15, "")]
+#[test_case("0o178", 15, "8")]
+#[test_case("0b102", 2, "2")]
+#[test_case("1_000;", 1000, ";")]
+#[test_case("1__;", 1, ";")]
+#[test_case("0x", 0, "x")]
+#[test_case("0xg", 0, "xg")]
+#[test_case("-0x", 0, "x"; "neg_hex_no_digits")]
+#[test_case("0x__", 0, "x__")]
+#[test_case("0X10", 0, "X10")]
+#[test_case("42.0", 42, ".0")]
+#[test_case("12³45", 12, "³45"; "super3")]
+#[test_case("1 2", 1, " 2")]
+fn test_strtoint_prefix_i32(s: &str, x: i32, rest: &str) {
+    assert_eq!(strtoint_prefix::<i32>(s).unwrap(), (x, rest));
+    assert_eq!(i32::strtoint_prefix(s).unwrap(), (x, s.len() - rest.len()));
+}
+
+#[test_case("", StrToIntError::NoDigits; "empty")]
+#[test_case("+", StrToIntError::NoDigits; "plus")]
+#[test_case("px", StrToIntError::InvalidCharacter {c: 'p', position: 0, reason: InvalidReason::InvalidDigit})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("-1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus}; "neg1")]
+#[test_case("+-1", StrToIntError::InvalidCharacter {c: '-', position: 1, reason: InvalidReason::MisplacedSign}; "plus_minus")]
+#[test_case(" 1", StrToIntError::InvalidCharacter {c: ' ', position: 0, reason: InvalidReason::UnexpectedCharacter}; "space1")]
+#[test_case("é", StrToIntError::InvalidCharacter {c: 'é', position: 0, reason: InvalidReason::UnexpectedCharacter}; "e_acute")]
+#[test_case("256px", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
+fn test_strtoint_prefix_u8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_prefix::<u8>(s).unwrap_err(), err);
+}
+
+#[test]
+fn test_strtoint_prefix_nonzero() {
+    assert_eq!(
+        strtoint_prefix::<NonZeroU32>("7 days").unwrap(),
+        (NonZeroU32::new(7).unwrap(), " days")
+    );
+    assert_eq!(
+        strtoint_prefix::<NonZeroU32>("0x").unwrap_err(),
+        StrToIntError::Zero
+    );
+}
+
+#[test_case("1_2", 12, "")]
+#[test_case("1_x", 1, "_x")]
+#[test_case("1__2", 1, "__2")]
+#[test_case("12_", 12, "_")]
+#[test_case("0x_1", 0, "x_1")]
+fn test_strtoint_prefix_between_digits(s: &str, x: u32, rest: &str) {
+    let opts = ParseOptions::new().underscores(Underscores::BetweenDigits);
+    assert_eq!(strtoint_prefix_with::<u32>(s, &opts).unwrap(), (x, rest));
+}
+
+#[test]
+fn test_strtoint_prefix_with_forbidden_underscores() {
+    let opts = ParseOptions::new().underscores(Underscores::Forbidden);
+    assert_eq!(
+        strtoint_prefix_with::<u32>("1_000", &opts).unwrap(),
+        (1, "_000")
+    );
+}
+
+#[test]
+fn test_strtoint_advance() {
+    let mut s = "10,0x20,-0b11;";
+    assert_eq!(strtoint_advance::<i64>(&mut s).unwrap(), 10);
+    assert_eq!(s, ",0x20,-0b11;");
+    s = &s[1..];
+    assert_eq!(strtoint_advance::<i64>(&mut s).unwrap(), 32);
+    s = &s[1..];
+    assert_eq!(strtoint_advance::<i64>(&mut s).unwrap(), -3);
+    assert_eq!(s, ";");
+    assert_eq!(
+        strtoint_advance::<i64>(&mut s).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: ';',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        }
+    );
+    assert_eq!(s, ";");
+}
+
+#[test]
+fn test_strtoint_advance_with() {
+    let opts = ParseOptions::new().plus_sign(false);
+    let mut s = "+1";
+    assert!(strtoint_advance_with::<i64>(&mut s, &opts).is_err());
+    assert_eq!(s, "+1");
+    s = "1+1";
+    assert_eq!(strtoint_advance_with::<i64>(&mut s, &opts).unwrap(), 1);
+    assert_eq!(s, "+1");
+}
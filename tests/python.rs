@@ -0,0 +1,152 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_bytes_with, strtoint_prefix_with, strtoint_with, InvalidReason, LeadingZeros,
+    ParseOptions, StrToIntError, Underscores,
+};
+use test_case::test_case;
+
+#[test_case("0", 0)]
+#[test_case("42", 42)]
+#[test_case("+42", 42)]
+#[test_case("-42", -42)]
+#[test_case("00", 0)]
+#[test_case("0_0", 0)]
+#[test_case("0_00", 0)]
+#[test_case("-000", 0)]
+#[test_case("1_000", 1000)]
+#[test_case("0x1F", 31)]
+#[test_case("0X1f", 31; "upper_x")]
+#[test_case("0x_1F", 31)]
+#[test_case("0x1_F", 31)]
+#[test_case("-0x_1F", -31)]
+#[test_case("0o17", 15)]
+#[test_case("0O17", 15; "upper_o")]
+#[test_case("0o_1_7", 15)]
+#[test_case("0b101", 5)]
+#[test_case("0B101", 5; "upper_b")]
+#[test_case("0b_1_0_1", 5)]
+#[test_case("0x00", 0)]
+#[test_case("0o0017", 15)]
+#[test_case(" 42 ", 42)]
+#[test_case(" \t\n\x0B\x0C\r42\r\n", 42; "ascii_whitespace")]
+#[test_case("\u{85}1", 1; "next_line")]
+#[test_case("\u{A0} 12\u{3000}", 12; "unicode_whitespace")]
+#[test_case("\u{2028}-7\u{2029}", -7; "line_separators")]
+#[test_case("\u{1C}5", 5; "file_separator")]
+#[test_case("\u{1D}\u{1E}5\u{1F}", 5; "information_separators")]
+#[test_case("١٢", 12; "arabic_indic")]
+#[test_case("१_०००", 1000; "devanagari")]
+#[test_case("٠x1F", 31; "arabic_indic_zero_prefix")]
+#[test_case("0x١", 1; "arabic_indic_hex_digit")]
+#[test_case("0b١٠", 2; "arabic_indic_binary_digits")]
+#[test_case("٠", 0; "arabic_indic_zero")]
+#[test_case("٠٠", 0; "arabic_indic_zeros")]
+#[test_case("１２", 12; "fullwidth")]
+fn test_python(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::python()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("   ", StrToIntError::NoDigits; "whitespace")]
+#[test_case("-", StrToIntError::NoDigits; "minus")]
+#[test_case("0x", StrToIntError::NoDigits)]
+#[test_case("0x_", StrToIntError::NoDigits; "prefix_underscore")]
+#[test_case("0x__1", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("0x___1___", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1__0", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1_", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1_ ", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore}; "underscore_before_whitespace")]
+#[test_case("01", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("0644", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case(" -0_1", StrToIntError::InvalidCharacter {c: '0', position: 2, reason: InvalidReason::LeadingZero})]
+#[test_case("٠١", StrToIntError::InvalidCharacter {c: '٠', position: 0, reason: InvalidReason::LeadingZero}; "arabic_indic_leading_zero")]
+#[test_case("+ 1", StrToIntError::InvalidCharacter {c: ' ', position: 1, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("1 2", StrToIntError::InvalidCharacter {c: ' ', position: 1, reason: InvalidReason::TrailingCharacter})]
+#[test_case("\u{FEFF}1", StrToIntError::InvalidCharacter {c: '\u{FEFF}', position: 0, reason: InvalidReason::UnexpectedCharacter}; "byte_order_mark")]
+#[test_case("\u{200B}1", StrToIntError::InvalidCharacter {c: '\u{200B}', position: 0, reason: InvalidReason::UnexpectedCharacter}; "zero_width_space")]
+#[test_case("0b12", StrToIntError::InvalidCharacter {c: '2', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("0b1٢", StrToIntError::InvalidCharacter {c: '٢', position: 3, reason: InvalidReason::TrailingCharacter}; "arabic_indic_invalid_binary_digit")]
+#[test_case("0xg", StrToIntError::InvalidCharacter {c: 'g', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("1e5", StrToIntError::InvalidCharacter {c: 'e', position: 1, reason: InvalidReason::InvalidDigit})]
+fn test_python_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::python()), Err(err));
+}
+
+#[test]
+fn test_python_bytes() {
+    let opts = ParseOptions::python();
+    assert_eq!(strtoint_bytes_with::<u16>(b" 0O_17\n", &opts), Ok(15));
+    assert_eq!(
+        strtoint_bytes_with::<u16>(b"\xA012", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '\u{A0}',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        })
+    );
+}
+
+#[test]
+fn test_python_prefix() {
+    let opts = ParseOptions::python();
+    assert_eq!(
+        strtoint_prefix_with::<u16>(" 42  rest", &opts),
+        Ok((42, "  rest"))
+    );
+}
+
+#[test]
+fn test_trailing_whitespace_only() {
+    let opts = ParseOptions::new().trailing_whitespace(true);
+    assert_eq!(strtoint_with::<u8>("42 \n", &opts), Ok(42));
+    assert_eq!(
+        strtoint_with::<u8>(" 42", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: ' ',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        })
+    );
+}
+
+#[test]
+fn test_unicode_whitespace_needs_leading_whitespace() {
+    let opts = ParseOptions::new().unicode_whitespace(true);
+    assert_eq!(
+        strtoint_with::<u8>("\u{3000}42", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '\u{3000}',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        })
+    );
+}
+
+#[test]
+fn test_unicode_digits_off() {
+    assert_eq!(
+        strtoint_with::<u8>("١٢", &ParseOptions::new()),
+        Err(StrToIntError::InvalidCharacter {
+            c: '١',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        })
+    );
+}
+
+#[test_case("0x_1F", Ok(31))]
+#[test_case("1_0", Ok(10))]
+#[test_case("_1", Err(StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore}))]
+#[test_case("0x__1", Err(StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore}))]
+fn test_python_underscores(s: &str, r: Result<u32, StrToIntError>) {
+    let opts = ParseOptions::new().underscores(Underscores::Python);
+    assert_eq!(strtoint_with::<u32>(s, &opts), r);
+}
+
+#[test]
+fn test_leading_zeros_with_prefix() {
+    let opts = ParseOptions::new().leading_zeros(LeadingZeros::AllZeros);
+    assert_eq!(strtoint_with::<u32>("0x01", &opts), Ok(1));
+    assert_eq!(strtoint_with::<u32>("10", &opts), Ok(10));
+}
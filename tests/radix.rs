@@ -0,0 +1,132 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_radix, strtoint_with, InvalidReason, OutOfRangeKind, ParseOptions, PrefixConflict,
+    PrefixRule, StrToIntError, Underscores,
+};
+use test_case::test_case;
+
+#[test_case("0", 16, 0)]
+#[test_case("7f", 16, 127)]
+#[test_case("7F", 16, 127; "upper_7f")]
+#[test_case("0x7f", 16, 127; "prefixed_7f")]
+#[test_case("-80", 16, -128)]
+#[test_case("-0x80", 16, -128; "neg_prefixed_80")]
+#[test_case("+7f", 16, 127; "pos_7f")]
+#[test_case("1010", 2, 10)]
+#[test_case("0b1010", 2, 10; "prefixed_1010")]
+#[test_case("-0o17", 8, -15)]
+#[test_case("17", 8, 15)]
+#[test_case("1_0", 3, 3)]
+#[test_case("2a", 11, 32)]
+#[test_case("-3k", 36, -128)]
+#[test_case("3j", 36, 127)]
+#[test_case("42", 10, 42)]
+fn test_strtoint_radix(s: &str, radix: u32, x: i8) {
+    assert_eq!(strtoint_radix::<i8>(s, radix).unwrap(), x);
+}
+
+#[test_case("", 16, StrToIntError::NoDigits)]
+#[test_case("0x", 16, StrToIntError::NoDigits; "bare_prefix")]
+#[test_case("_ff", 16, StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("fg", 16, StrToIntError::InvalidCharacter {c: 'g', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0b1", 16, StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::ConflictingPrefix})]
+#[test_case("+0o1", 16, StrToIntError::InvalidCharacter {c: 'o', position: 2, reason: InvalidReason::ConflictingPrefix})]
+#[test_case("0x1", 10, StrToIntError::InvalidCharacter {c: 'x', position: 1, reason: InvalidReason::ConflictingPrefix})]
+#[test_case("18", 8, StrToIntError::InvalidCharacter {c: '8', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("100", 16, StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
+#[test_case("7w", 36, StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 1})]
+fn test_strtoint_radix_u8_err(s: &str, radix: u32, err: StrToIntError) {
+    assert_eq!(strtoint_radix::<u8>(s, radix).unwrap_err(), err);
+}
+
+#[test_case(0)]
+#[test_case(1)]
+#[test_case(37)]
+#[should_panic(expected = "radix must be in the range 2..=36")]
+fn test_strtoint_radix_bad_radix(radix: u32) {
+    let _ = strtoint_radix::<u8>("1", radix);
+}
+
+#[test_case(PrefixRule::Optional, PrefixConflict::Override, "0b1", Ok(1))]
+#[test_case(PrefixRule::Optional, PrefixConflict::Ignore, "0b1", Ok(0xB1))]
+#[test_case(PrefixRule::Optional, PrefixConflict::Error, "0b1", Err(StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::ConflictingPrefix}))]
+#[test_case(PrefixRule::Optional, PrefixConflict::Ignore, "0o1", Err(StrToIntError::InvalidCharacter {c: 'o', position: 1, reason: InvalidReason::InvalidDigit}))]
+#[test_case(PrefixRule::Optional, PrefixConflict::Error, "b1", Ok(0xB1))]
+#[test_case(PrefixRule::Required, PrefixConflict::Override, "0xb1", Ok(0xB1))]
+#[test_case(PrefixRule::Required, PrefixConflict::Override, "0b1", Ok(1))]
+#[test_case(PrefixRule::Required, PrefixConflict::Override, "-b1", Err(StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::MissingPrefix}))]
+#[test_case(PrefixRule::Required, PrefixConflict::Ignore, "0b1", Err(StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::MissingPrefix}))]
+#[test_case(PrefixRule::Required, PrefixConflict::Error, "0o1", Err(StrToIntError::InvalidCharacter {c: 'o', position: 1, reason: InvalidReason::ConflictingPrefix}))]
+#[test_case(
+    PrefixRule::Required,
+    PrefixConflict::Error,
+    "",
+    Err(StrToIntError::NoDigits)
+)]
+#[test_case(
+    PrefixRule::Required,
+    PrefixConflict::Error,
+    "+",
+    Err(StrToIntError::NoDigits)
+)]
+#[test_case(PrefixRule::Forbidden, PrefixConflict::Override, "0b1", Ok(0xB1))]
+#[test_case(PrefixRule::Forbidden, PrefixConflict::Override, "b1", Ok(0xB1))]
+#[test_case(PrefixRule::Forbidden, PrefixConflict::Override, "0xb1", Err(StrToIntError::InvalidCharacter {c: 'x', position: 1, reason: InvalidReason::InvalidDigit}))]
+fn test_prefix_rules_hex(
+    rule: PrefixRule,
+    conflict: PrefixConflict,
+    s: &str,
+    r: Result<i32, StrToIntError>,
+) {
+    let opts = ParseOptions::new()
+        .default_radix(16)
+        .radix_prefix(rule)
+        .prefix_conflict(conflict);
+    assert_eq!(strtoint_with::<i32>(s, &opts), r);
+}
+
+#[test]
+fn test_default_radix_disabled_prefix() {
+    let opts = ParseOptions::new()
+        .default_radix(16)
+        .hex_prefix(false)
+        .radix_prefix(PrefixRule::Required);
+    assert_eq!(
+        strtoint_with::<u32>("0x10", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '0',
+            position: 0,
+            reason: InvalidReason::MissingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_required_prefix_decimal_default() {
+    let opts = ParseOptions::new().radix_prefix(PrefixRule::Required);
+    assert_eq!(strtoint_with::<u32>("0o10", &opts), Ok(8));
+    assert_eq!(
+        strtoint_with::<u32>("10", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '1',
+            position: 0,
+            reason: InvalidReason::MissingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_default_radix_underscores() {
+    let opts = ParseOptions::new()
+        .default_radix(16)
+        .underscores(Underscores::BetweenDigits);
+    assert_eq!(strtoint_with::<u32>("dead_beef", &opts), Ok(0xDEAD_BEEF));
+    assert_eq!(
+        strtoint_with::<u32>("0x_1", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '_',
+            position: 2,
+            reason: InvalidReason::LeadingUnderscore
+        })
+    );
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_all_errors_with, strtoint_bytes_with, strtoint_prefix_with, strtoint_with, ErrorArray,
+    InvalidReason, OutOfRangeKind, ParseOptions, PrefixRule, StrToIntError,
+};
+use test_case::test_case;
+
+fn masm() -> ParseOptions {
+    ParseOptions::new().radix_suffixes(true)
+}
+
+#[test_case("0FFh", 255)]
+#[test_case("0ffH", 255; "upper_h")]
+#[test_case("1bh", 27)]
+#[test_case("1dh", 29)]
+#[test_case("1010b", 10)]
+#[test_case("1010B", 10; "upper_b")]
+#[test_case("1010y", 10)]
+#[test_case("17o", 15)]
+#[test_case("17q", 15)]
+#[test_case("17Q", 15; "upper_q")]
+#[test_case("12d", 12)]
+#[test_case("12t", 12)]
+#[test_case("12", 12)]
+#[test_case("-80h", -128)]
+#[test_case("+7Fh", 127)]
+#[test_case("0b1h", 177)]
+#[test_case("0x10", 16)]
+#[test_case("0b101", 5)]
+#[test_case("1_000d", 1000)]
+fn test_radix_suffix(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &masm()).unwrap(), x);
+}
+
+#[test_case("12d", 0x12D)]
+#[test_case("1bh", 0x1B)]
+#[test_case("101y", 5)]
+#[test_case("12t", 12)]
+#[test_case("17q", 15)]
+#[test_case("0FF", 0xFF)]
+#[test_case("1b", 0x1B)]
+fn test_radix_suffix_hex_default(s: &str, x: u32) {
+    let opts = masm().default_radix(16);
+    assert_eq!(strtoint_with::<u32>(s, &opts).unwrap(), x);
+}
+
+#[test_case("FFh", StrToIntError::InvalidCharacter {c: 'F', position: 0, reason: InvalidReason::NoLeadingDigit})]
+#[test_case("-bh", StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::NoLeadingDigit})]
+#[test_case("h", StrToIntError::InvalidCharacter {c: 'h', position: 0, reason: InvalidReason::InvalidDigit})]
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("0FGh", StrToIntError::InvalidCharacter {c: 'G', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("-12b", StrToIntError::InvalidCharacter {c: '2', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("19o", StrToIntError::InvalidCharacter {c: '9', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("12x", StrToIntError::InvalidCharacter {c: 'x', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("12 h", StrToIntError::InvalidCharacter {c: ' ', position: 2, reason: InvalidReason::TrailingCharacter})]
+#[test_case("_1h", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("80h", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -128, max: 127, position: 1})]
+#[test_case("-81h", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: -128, max: 127, position: 2})]
+fn test_radix_suffix_i8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i8>(s, &masm()).unwrap_err(), err);
+}
+
+#[test]
+fn test_radix_suffix_no_leading_digit_hex_default() {
+    let opts = masm().default_radix(16);
+    assert_eq!(
+        strtoint_with::<u32>("FF", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: 'F',
+            position: 0,
+            reason: InvalidReason::NoLeadingDigit
+        })
+    );
+}
+
+#[test_case("0FFh, 1", 255, ", 1")]
+#[test_case("1010b+1", 10, "+1")]
+#[test_case("12 h", 12, " h")]
+#[test_case("12d)", 12, ")")]
+#[test_case("19o", 1, "9o")]
+#[test_case("7", 7, "")]
+fn test_radix_suffix_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(strtoint_prefix_with::<u32>(s, &masm()).unwrap(), (x, rest));
+}
+
+#[test]
+fn test_radix_suffix_bytes() {
+    assert_eq!(strtoint_bytes_with::<u16>(b"0BEEFh", &masm()), Ok(0xBEEF));
+}
+
+#[test]
+fn test_radix_suffix_satisfies_required_prefix() {
+    let opts = masm().radix_prefix(PrefixRule::Required);
+    assert_eq!(strtoint_with::<u32>("10h", &opts), Ok(16));
+    assert_eq!(strtoint_with::<u32>("0x10", &opts), Ok(16));
+    assert_eq!(
+        strtoint_with::<u32>("10", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '1',
+            position: 0,
+            reason: InvalidReason::MissingPrefix
+        })
+    );
+}
+
+#[test]
+fn test_radix_suffix_all_errors() {
+    let mut errors = ErrorArray::<4>::new();
+    assert!(strtoint_all_errors_with::<u32>("FG_Zh", &masm(), &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [
+            StrToIntError::InvalidCharacter {
+                c: 'F',
+                position: 0,
+                reason: InvalidReason::NoLeadingDigit
+            },
+            StrToIntError::InvalidCharacter {
+                c: 'G',
+                position: 1,
+                reason: InvalidReason::InvalidDigit
+            },
+            StrToIntError::InvalidCharacter {
+                c: 'Z',
+                position: 3,
+                reason: InvalidReason::InvalidDigit
+            },
+        ]
+    );
+}
@@ -0,0 +1,299 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_c, strtoint_prefix_overflowing_with, strtoint_prefix_with, strtoint_with,
+    InvalidReason, OutOfRangeKind, Overflow, ParseOptions, StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case("0", 0, 0, 1)]
+#[test_case("42", 0, 42, 2)]
+#[test_case("  42", 0, 42, 4)]
+#[test_case(" \t\n\x0B\x0C\r7", 0, 7, 7; "all_whitespace")]
+#[test_case("+7", 0, 7, 2)]
+#[test_case("-7", 0, -7, 2)]
+#[test_case("  -7", 0, -7, 4)]
+#[test_case("0x1f", 0, 31, 4)]
+#[test_case("0X1F", 0, 31, 4; "upper_x")]
+#[test_case("-0x1F", 0, -31, 5)]
+#[test_case("017", 0, 15, 3)]
+#[test_case("-017", 0, -15, 4)]
+#[test_case("00", 0, 0, 2)]
+#[test_case("08", 0, 0, 1)]
+#[test_case("0x", 0, 0, 1)]
+#[test_case("0xg", 0, 0, 1)]
+#[test_case("0x-1", 0, 0, 1)]
+#[test_case(" -0x", 0, 0, 3)]
+#[test_case("0b1", 0, 0, 1)]
+#[test_case("0o7", 0, 0, 1)]
+#[test_case("12abc", 0, 12, 2)]
+#[test_case("1_000", 0, 1, 1)]
+#[test_case("42 ", 0, 42, 2)]
+#[test_case("42\n", 0, 42, 2; "trailing_newline")]
+#[test_case("1e5", 0, 1, 1)]
+#[test_case("10", 10, 10, 2)]
+#[test_case("017", 10, 17, 3; "base_10_leading_zero")]
+#[test_case("0x1F", 10, 0, 1; "base_10_hex_prefix")]
+#[test_case("0x1F", 16, 31, 4; "base_16_prefix")]
+#[test_case("0X1f", 16, 31, 4; "base_16_upper_prefix")]
+#[test_case("1F", 16, 31, 2; "base_16_no_prefix")]
+#[test_case("0x", 16, 0, 1; "base_16_bare_prefix")]
+#[test_case("-ff", 16, -255, 3; "base_16_negative")]
+#[test_case("017", 8, 15, 3; "base_8")]
+#[test_case("0x1", 8, 0, 1; "base_8_hex_prefix")]
+#[test_case("0o17", 8, 0, 1; "base_8_octal_prefix")]
+#[test_case("1012", 2, 5, 3; "base_2")]
+#[test_case("0b1", 2, 0, 1; "base_2_binary_prefix")]
+#[test_case("zz", 36, 1295, 2; "base_36")]
+#[test_case("0x1", 36, 1189, 3; "base_36_hex_prefix")]
+#[test_case("Zz!", 36, 1295, 2; "base_36_mixed_case")]
+fn test_strtoint_c(s: &str, base: u32, x: i64, end: usize) {
+    assert_eq!(strtoint_c::<i64>(s, base).unwrap(), (x, end, false));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("   ", StrToIntError::NoDigits; "whitespace")]
+#[test_case("+", StrToIntError::NoDigits; "plus")]
+#[test_case("-", StrToIntError::NoDigits; "minus")]
+#[test_case("abc", StrToIntError::InvalidCharacter {c: 'a', position: 0, reason: InvalidReason::InvalidDigit})]
+#[test_case(" - 1", StrToIntError::InvalidCharacter {c: ' ', position: 2, reason: InvalidReason::UnexpectedCharacter})]
+#[test_case("+-1", StrToIntError::InvalidCharacter {c: '-', position: 1, reason: InvalidReason::MisplacedSign})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+fn test_strtoint_c_no_number(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_c::<i64>(s, 0).unwrap_err(), err);
+}
+
+#[test_case(0, "  0x7f", 127)]
+#[test_case(0, "0177", 127)]
+#[test_case(0, "-0200", -128)]
+#[test_case(16, "0x7f", 127)]
+#[test_case(10, "-0128", -128)]
+fn test_strtol_full(base: u32, s: &str, x: i8) {
+    assert_eq!(strtoint_with::<i8>(s, &ParseOptions::strtol(base)), Ok(x));
+}
+
+#[test_case(0, "0x")]
+#[test_case(0, "08")]
+#[test_case(0, "1 ")]
+#[test_case(0, "1_0")]
+#[test_case(10, "0x1")]
+fn test_strtol_full_err(base: u32, s: &str) {
+    assert!(strtoint_with::<i8>(s, &ParseOptions::strtol(base)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2..=36")]
+fn test_strtol_base_1() {
+    let _ = ParseOptions::strtol(1);
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2..=36")]
+fn test_strtol_base_37() {
+    let _ = strtoint_c::<i32>("1", 37);
+}
+
+#[test]
+fn test_negate_unsigned_without_strtol() {
+    let opts = ParseOptions::new().negate_unsigned(true);
+    assert_eq!(strtoint_with::<u32>("-0x1", &opts), Ok(u32::MAX));
+    assert_eq!(strtoint_with::<u32>("-0", &opts), Ok(0));
+    assert_eq!(
+        strtoint_with::<u32>("-0x1_0000_0000", &opts),
+        Err(StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: u32::MAX.into(),
+            position: 13
+        })
+    );
+    let wrap = opts.overflow(Overflow::Wrap);
+    assert_eq!(strtoint_with::<u8>("-257", &wrap), Ok(255));
+    assert_eq!(strtoint_with::<u8>("257", &wrap), Ok(1));
+}
+
+#[test]
+fn test_leading_zero_octal_without_strtol() {
+    let opts = ParseOptions::new().leading_zero_octal(true);
+    assert_eq!(strtoint_with::<u32>("017", &opts), Ok(15));
+    assert_eq!(strtoint_with::<u32>("0", &opts), Ok(0));
+    assert_eq!(strtoint_with::<u32>("0_17", &opts), Ok(15));
+    assert_eq!(strtoint_with::<u32>("0x17", &opts), Ok(0x17));
+    assert_eq!(strtoint_with::<u32>("17", &opts), Ok(17));
+    assert_eq!(
+        strtoint_with::<u32>("019", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '9',
+            position: 2,
+            reason: InvalidReason::InvalidDigit
+        })
+    );
+}
+
+#[test]
+fn test_leading_whitespace_without_strtol() {
+    let opts = ParseOptions::new().leading_whitespace(true);
+    assert_eq!(strtoint_with::<u32>(" \t0x17", &opts), Ok(0x17));
+    assert_eq!(
+        strtoint_with::<u32>(" 17 ", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: ' ',
+            position: 3,
+            reason: InvalidReason::TrailingCharacter
+        })
+    );
+    assert_eq!(
+        strtoint_with::<u32>("\u{A0}17", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '\u{A0}',
+            position: 0,
+            reason: InvalidReason::UnexpectedCharacter
+        })
+    );
+}
+
+/// Tests of the documented semantics of `strtol()` at the boundaries of each
+/// signed type
+macro_rules! signed_tests {
+    ($($name:ident: $t:ty),* $(,)?) => {
+      $(
+        mod $name {
+            use super::*;
+
+            fn clamp() -> ParseOptions {
+                ParseOptions::strtol(0).overflow(Overflow::Saturate)
+            }
+
+            #[test]
+            fn test_bounds() {
+                let max = <$t>::MAX.to_string();
+                let min = <$t>::MIN.to_string();
+                assert_eq!(strtoint_c::<$t>(&max, 0), Ok((<$t>::MAX, max.len(), false)));
+                assert_eq!(strtoint_c::<$t>(&min, 0), Ok((<$t>::MIN, min.len(), false)));
+                let hex = format!("{:#x}", <$t>::MAX);
+                assert_eq!(strtoint_c::<$t>(&hex, 0), Ok((<$t>::MAX, hex.len(), false)));
+                let oct = format!("0{:o}", <$t>::MAX);
+                assert_eq!(strtoint_c::<$t>(&oct, 0), Ok((<$t>::MAX, oct.len(), false)));
+                let neg_hex = format!("-{:#x}", <$t>::MIN.unsigned_abs());
+                assert_eq!(strtoint_c::<$t>(&neg_hex, 0), Ok((<$t>::MIN, neg_hex.len(), false)));
+                assert_eq!(strtoint_c::<$t>(" -1;", 0), Ok((-1, 3, false)));
+            }
+
+            #[test]
+            fn test_overflow() {
+                let over = format!("{}0", <$t>::MAX);
+                let under = format!("{}0", <$t>::MIN);
+                assert_eq!(
+                    strtoint_prefix_with::<$t>(&over, &ParseOptions::strtol(0)),
+                    Err(StrToIntError::OutOfRange {
+                        kind: OutOfRangeKind::TooLarge,
+                        min: i128::try_from(<$t>::MIN).unwrap(),
+                        max: u128::try_from(<$t>::MAX).unwrap(),
+                        position: over.len() - 1,
+                    })
+                );
+                assert_eq!(
+                    strtoint_prefix_with::<$t>(&under, &ParseOptions::strtol(0)),
+                    Err(StrToIntError::OutOfRange {
+                        kind: OutOfRangeKind::TooSmall,
+                        min: i128::try_from(<$t>::MIN).unwrap(),
+                        max: u128::try_from(<$t>::MAX).unwrap(),
+                        position: under.len() - 1,
+                    })
+                );
+                assert_eq!(strtoint_prefix_with::<$t>(&over, &clamp()), Ok((<$t>::MAX, "")));
+                assert_eq!(strtoint_prefix_with::<$t>(&under, &clamp()), Ok((<$t>::MIN, "")));
+                let over_hex = format!("{:#x}0 ", <$t>::MAX);
+                assert_eq!(strtoint_prefix_with::<$t>(&over_hex, &clamp()), Ok((<$t>::MAX, " ")));
+                assert_eq!(strtoint_c::<$t>(&over, 0), Ok((<$t>::MAX, over.len(), true)));
+                assert_eq!(strtoint_c::<$t>(&under, 0), Ok((<$t>::MIN, under.len(), true)));
+                assert_eq!(
+                    strtoint_c::<$t>(&over_hex, 0),
+                    Ok((<$t>::MAX, over_hex.len() - 1, true))
+                );
+                assert_eq!(
+                    strtoint_prefix_overflowing_with::<$t>(&over_hex, &clamp()),
+                    Ok((<$t>::MAX, " ", true))
+                );
+            }
+        }
+      )*
+    };
+}
+
+/// Tests of the documented semantics of `strtoul()` at the boundaries of
+/// each unsigned type
+macro_rules! unsigned_tests {
+    ($($name:ident: $t:ty),* $(,)?) => {
+      $(
+        mod $name {
+            use super::*;
+
+            fn clamp() -> ParseOptions {
+                ParseOptions::strtol(0).overflow(Overflow::Saturate)
+            }
+
+            #[test]
+            fn test_bounds() {
+                let max = <$t>::MAX.to_string();
+                assert_eq!(strtoint_c::<$t>(&max, 0), Ok((<$t>::MAX, max.len(), false)));
+                let hex = format!("{:#X}", <$t>::MAX);
+                assert_eq!(strtoint_c::<$t>(&hex, 0), Ok((<$t>::MAX, hex.len(), false)));
+                let oct = format!("0{:o}", <$t>::MAX);
+                assert_eq!(strtoint_c::<$t>(&oct, 0), Ok((<$t>::MAX, oct.len(), false)));
+                assert_eq!(strtoint_c::<$t>("0", 0), Ok((0, 1, false)));
+            }
+
+            #[test]
+            fn test_negation() {
+                let neg_max = format!("-{}", <$t>::MAX);
+                assert_eq!(strtoint_c::<$t>("-1", 0), Ok((<$t>::MAX, 2, false)));
+                assert_eq!(strtoint_c::<$t>(" -0x2", 0), Ok((<$t>::MAX - 1, 5, false)));
+                assert_eq!(strtoint_c::<$t>("-0", 0), Ok((0, 2, false)));
+                assert_eq!(strtoint_c::<$t>(&neg_max, 0), Ok((1, neg_max.len(), false)));
+            }
+
+            #[test]
+            fn test_overflow() {
+                let over = format!("{}0", <$t>::MAX);
+                let neg_over = format!("-{}0", <$t>::MAX);
+                let err = StrToIntError::OutOfRange {
+                    kind: OutOfRangeKind::TooLarge,
+                    min: 0,
+                    max: u128::try_from(<$t>::MAX).unwrap(),
+                    position: over.len() - 1,
+                };
+                let strtoul = ParseOptions::strtol(0);
+                assert_eq!(strtoint_prefix_with::<$t>(&over, &strtoul), Err(err));
+                assert_eq!(strtoint_prefix_with::<$t>(&neg_over, &strtoul), Err(err.with_offset(1)));
+                assert_eq!(strtoint_prefix_with::<$t>(&over, &clamp()), Ok((<$t>::MAX, "")));
+                assert_eq!(strtoint_prefix_with::<$t>(&neg_over, &clamp()), Ok((<$t>::MAX, "")));
+                assert_eq!(strtoint_c::<$t>(&over, 0), Ok((<$t>::MAX, over.len(), true)));
+                assert_eq!(strtoint_c::<$t>(&neg_over, 0), Ok((<$t>::MAX, neg_over.len(), true)));
+                let over_rest = format!("{over}x");
+                assert_eq!(
+                    strtoint_prefix_overflowing_with::<$t>(&over_rest, &clamp()),
+                    Ok((<$t>::MAX, "x", true))
+                );
+            }
+        }
+      )*
+    };
+}
+
+signed_tests! {
+    i8_tests: i8,
+    i16_tests: i16,
+    i32_tests: i32,
+    i64_tests: i64,
+    i128_tests: i128,
+    isize_tests: isize,
+}
+
+unsigned_tests! {
+    u8_tests: u8,
+    u16_tests: u16,
+    u32_tests: u32,
+    u64_tests: u64,
+    u128_tests: u128,
+    usize_tests: usize,
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+use core::num::NonZeroU8;
+use strtoint::{
+    strtoint_prefix_with, strtoint_typed, strtoint_with, IntSuffix, InvalidReason, OutOfRangeKind,
+    ParseOptions, StrToIntError, TypedInt,
+};
+use test_case::test_case;
+
+#[test_case("255", 255)]
+#[test_case("255u8", 255)]
+#[test_case("0xffu8", 255)]
+#[test_case("0x_ff_u8", 255)]
+#[test_case("0b1u8", 1)]
+#[test_case("0o7u8", 7)]
+#[test_case("1_u8", 1)]
+fn test_type_suffixes_u8(s: &str, x: u8) {
+    let opts = ParseOptions::new().type_suffixes(true);
+    assert_eq!(strtoint_with::<u8>(s, &opts).unwrap(), x);
+}
+
+#[test_case("256u8", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
+#[test_case("255i8", StrToIntError::SuffixMismatch {suffix: IntSuffix::I8, position: 3})]
+#[test_case("1usize", StrToIntError::SuffixMismatch {suffix: IntSuffix::Usize, position: 1})]
+#[test_case("0x1_u16", StrToIntError::SuffixMismatch {suffix: IntSuffix::U16, position: 4})]
+#[test_case("0b12", StrToIntError::InvalidCharacter {c: '2', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("0b12u8", StrToIntError::InvalidCharacter {c: '2', position: 3, reason: InvalidReason::InvalidDigit}; "0b12u8_invalid_digit")]
+#[test_case("0o8u8", StrToIntError::InvalidCharacter {c: '8', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("0xfg", StrToIntError::InvalidCharacter {c: 'g', position: 3, reason: InvalidReason::InvalidSuffix})]
+#[test_case("1u7", StrToIntError::InvalidCharacter {c: 'u', position: 1, reason: InvalidReason::InvalidSuffix})]
+#[test_case("1u8x", StrToIntError::InvalidCharacter {c: 'u', position: 1, reason: InvalidReason::InvalidSuffix})]
+#[test_case("1u8 ", StrToIntError::InvalidCharacter {c: ' ', position: 3, reason: InvalidReason::TrailingCharacter}; "trailing_space")]
+#[test_case("1U8", StrToIntError::InvalidCharacter {c: 'U', position: 1, reason: InvalidReason::InvalidSuffix})]
+#[test_case("u8", StrToIntError::InvalidCharacter {c: 'u', position: 0, reason: InvalidReason::InvalidDigit})]
+#[test_case("0xu8", StrToIntError::InvalidCharacter {c: 'u', position: 2, reason: InvalidReason::InvalidDigit})]
+#[test_case("1e3", StrToIntError::InvalidCharacter {c: 'e', position: 1, reason: InvalidReason::InvalidSuffix})]
+fn test_type_suffixes_u8_err(s: &str, err: StrToIntError) {
+    let opts = ParseOptions::new().type_suffixes(true);
+    assert_eq!(strtoint_with::<u8>(s, &opts).unwrap_err(), err);
+}
+
+#[test]
+fn test_type_suffixes_disabled() {
+    assert_eq!(
+        strtoint_with::<u8>("1u8", &ParseOptions::new()).unwrap_err(),
+        StrToIntError::InvalidCharacter {
+            c: 'u',
+            position: 1,
+            reason: InvalidReason::InvalidDigit
+        }
+    );
+}
+
+#[test]
+fn test_type_suffixes_nonzero() {
+    let opts = ParseOptions::new().type_suffixes(true);
+    assert_eq!(
+        strtoint_with::<NonZeroU8>("7u8", &opts).unwrap(),
+        NonZeroU8::new(7).unwrap()
+    );
+}
+
+#[test]
+fn test_type_suffixes_prefix() {
+    let opts = ParseOptions::new().type_suffixes(true);
+    assert_eq!(
+        strtoint_prefix_with::<i64>("-5i64, rest", &opts).unwrap(),
+        (-5, ", rest")
+    );
+    assert_eq!(
+        strtoint_prefix_with::<i64>("5i64x", &opts).unwrap(),
+        (5, "i64x")
+    );
+    assert_eq!(
+        strtoint_prefix_with::<i64>("5u64;", &opts).unwrap_err(),
+        StrToIntError::SuffixMismatch {
+            suffix: IntSuffix::U64,
+            position: 1
+        }
+    );
+}
+
+#[test_case("0", TypedInt::I32(0), false)]
+#[test_case("-1", TypedInt::I32(-1), false; "neg1")]
+#[test_case("42", TypedInt::I32(42), false)]
+#[test_case("42i32", TypedInt::I32(42), true)]
+#[test_case("255u8", TypedInt::U8(255), true)]
+#[test_case("-128i8", TypedInt::I8(-128), true)]
+#[test_case("0x10_i64", TypedInt::I64(16), true)]
+#[test_case("0b1_u128", TypedInt::U128(1), true)]
+#[test_case("7usize", TypedInt::Usize(7), true)]
+#[test_case("-7isize", TypedInt::Isize(-7), true)]
+#[test_case("65535u16", TypedInt::U16(65535), true)]
+fn test_strtoint_typed(s: &str, x: TypedInt, suffixed: bool) {
+    assert_eq!(strtoint_typed(s, IntSuffix::I32).unwrap(), (x, suffixed));
+}
+
+#[test_case("256u8", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 2})]
+#[test_case("-1u8", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("0b12u8", StrToIntError::InvalidCharacter {c: '2', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("1u9", StrToIntError::InvalidCharacter {c: 'u', position: 1, reason: InvalidReason::InvalidSuffix})]
+#[test_case("4294967296", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: -2147483648, max: 2147483647, position: 9}; "default_overflow")]
+fn test_strtoint_typed_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_typed(s, IntSuffix::I32).unwrap_err(), err);
+}
+
+#[test]
+fn test_strtoint_typed_default() {
+    assert_eq!(
+        strtoint_typed("4294967296", IntSuffix::U64).unwrap(),
+        (TypedInt::U64(4294967296), false)
+    );
+}
+
+#[test_case(TypedInt::U8(255), "255u8")]
+#[test_case(TypedInt::I64(-16), "-16i64")]
+#[test_case(TypedInt::Usize(0), "0usize")]
+fn test_display_typed_int(x: TypedInt, s: &str) {
+    assert_eq!(x.to_string(), s);
+    assert_eq!(strtoint_typed(s, IntSuffix::I32).unwrap(), (x, true));
+}
+
+#[test]
+fn test_int_suffix_display() {
+    for suffix in IntSuffix::ALL {
+        assert_eq!(suffix.to_string(), suffix.as_str());
+    }
+}
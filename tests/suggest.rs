@@ -0,0 +1,81 @@
+#![cfg(test)]
+#![cfg(feature = "alloc")]
+use strtoint::{
+    strtoint, strtoint_with, suggest, suggest_with, Mistake, ParseOptions, Underscores,
+};
+use test_case::test_case;
+
+#[test_case("0X1F", "0x1F", Mistake::UppercasePrefix)]
+#[test_case("-0B101", "-0b101", Mistake::UppercasePrefix; "neg_upper_0B")]
+#[test_case("0O17", "0o17", Mistake::UppercasePrefix)]
+#[test_case("0h1F", "0x1F", Mistake::UnsupportedPrefix)]
+#[test_case("+0H1F", "+0x1F", Mistake::UnsupportedPrefix; "pos_upper_0H")]
+#[test_case("0d42", "42", Mistake::UnsupportedPrefix)]
+#[test_case("-0D42", "-42", Mistake::UnsupportedPrefix; "neg_upper_0D")]
+#[test_case("ff00", "0xff00", Mistake::MissingHexPrefix)]
+#[test_case("-7FFF_FFFF", "-0x7FFF_FFFF", Mistake::MissingHexPrefix; "neg_hex")]
+#[test_case("1O0", "100", Mistake::LetterForDigit)]
+#[test_case("l0", "10", Mistake::LetterForDigit)]
+#[test_case("2lO", "210", Mistake::LetterForDigit)]
+#[test_case("42u32", "42", Mistake::TypeSuffix)]
+#[test_case("0x10_i64", "0x10", Mistake::TypeSuffix)]
+#[test_case("1,000", "1_000", Mistake::DigitSeparator)]
+#[test_case("-12,345,678", "-12_345_678", Mistake::DigitSeparator; "neg_commas")]
+#[test_case("1'000", "1_000", Mistake::DigitSeparator; "apostrophe")]
+#[test_case("1 000", "1_000", Mistake::DigitSeparator; "space")]
+fn test_suggest(s: &str, replacement: &str, mistake: Mistake) {
+    let e = strtoint::<i64>(s).unwrap_err();
+    let sugg = suggest::<i64>(s, &e).unwrap();
+    assert_eq!(sugg.replacement(), replacement);
+    assert_eq!(sugg.mistake(), mistake);
+    assert!(strtoint::<i64>(sugg.replacement()).is_ok());
+}
+
+#[test_case("42.0")]
+#[test_case("1,5"; "decimal_comma")]
+#[test_case("1,0000"; "long_group")]
+#[test_case(",000"; "leading_comma")]
+#[test_case("0bff")]
+#[test_case("xyz")]
+#[test_case("0x1G")]
+#[test_case("")]
+#[test_case("99999999999999999999999")]
+fn test_no_suggestion(s: &str) {
+    let e = strtoint::<i64>(s).unwrap_err();
+    assert_eq!(suggest::<i64>(s, &e), None);
+}
+
+#[test]
+fn test_suggestion_must_fit_type() {
+    let e = strtoint::<u8>("ff00").unwrap_err();
+    assert_eq!(suggest::<u8>("ff00", &e), None);
+    let e = strtoint::<u8>("-0X1").unwrap_err();
+    assert_eq!(suggest::<u8>("-0X1", &e), None);
+}
+
+#[test]
+fn test_suggest_with_forbidden_underscores() {
+    let opts = ParseOptions::new().underscores(Underscores::Forbidden);
+    let e = strtoint_with::<u32>("1,000", &opts).unwrap_err();
+    let sugg = suggest_with::<u32>("1,000", &e, &opts).unwrap();
+    assert_eq!(sugg.replacement(), "1000");
+    assert_eq!(sugg.mistake(), Mistake::DigitSeparator);
+}
+
+#[test]
+fn test_display_suggestion() {
+    let e = strtoint::<u32>("0h1F").unwrap_err();
+    assert_eq!(
+        suggest::<u32>("0h1F", &e).unwrap().to_string(),
+        "did you mean `0x1F`? (only `0x`, `0o`, and `0b` base prefixes are supported)"
+    );
+}
+
+#[test]
+fn test_into_replacement() {
+    let e = strtoint::<u32>("1,000").unwrap_err();
+    assert_eq!(
+        suggest::<u32>("1,000", &e).unwrap().into_replacement(),
+        "1_000"
+    );
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+use strtoint::{strtoint_with, InvalidReason, LeadingZeros, ParseOptions, StrToIntError};
+use test_case::test_case;
+
+#[test_case("0", 0)]
+#[test_case("+0", 0)]
+#[test_case("-0", 0)]
+#[test_case("99", 99)]
+#[test_case("+99", 99)]
+#[test_case("-17", -17)]
+#[test_case("42", 42)]
+#[test_case("1_000", 1000)]
+#[test_case("5_349_221", 5_349_221)]
+#[test_case("53_49_221", 5_349_221; "indian_grouping")]
+#[test_case("1_2_3_4_5", 12345)]
+#[test_case("0xDEADBEEF", 0xDEAD_BEEF)]
+#[test_case("0xdeadbeef", 0xDEAD_BEEF; "lowercase_hex")]
+#[test_case("0xdead_beef", 0xDEAD_BEEF)]
+#[test_case("0x1F90", 8080)]
+#[test_case("0x00", 0)]
+#[test_case("0o01234567", 0o1234567)]
+#[test_case("0o755", 0o755)]
+#[test_case("0b11010110", 0b1101_0110)]
+#[test_case("0b1101_0110", 0b1101_0110; "binary_underscore")]
+#[test_case("9223372036854775807", i64::MAX)]
+#[test_case("-9223372036854775808", i64::MIN)]
+fn test_toml(s: &str, x: i64) {
+    assert_eq!(strtoint_with::<i64>(s, &ParseOptions::toml()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("+", StrToIntError::NoDigits)]
+#[test_case("0x", StrToIntError::NoDigits)]
+#[test_case("-0x10", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("+0x10", StrToIntError::InvalidCharacter {c: '+', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("+0o7", StrToIntError::InvalidCharacter {c: '+', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("-0b1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("0X1F", StrToIntError::InvalidCharacter {c: 'X', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0O17", StrToIntError::InvalidCharacter {c: 'O', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0B1", StrToIntError::InvalidCharacter {c: 'B', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0644", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("00", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("0_0", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero})]
+#[test_case("-01", StrToIntError::InvalidCharacter {c: '0', position: 1, reason: InvalidReason::LeadingZero})]
+#[test_case("+007", StrToIntError::InvalidCharacter {c: '0', position: 1, reason: InvalidReason::LeadingZero})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1_", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1__0", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("0x_1", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("0x___1___", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1e3", StrToIntError::InvalidCharacter {c: 'e', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case(" 1", StrToIntError::InvalidCharacter {c: ' ', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+fn test_toml_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i64>(s, &ParseOptions::toml()), Err(err));
+}
+
+#[test]
+fn test_toml_unsigned() {
+    let opts = ParseOptions::toml();
+    assert_eq!(strtoint_with::<u16>("0x1F90", &opts), Ok(8080));
+    assert_eq!(
+        strtoint_with::<u16>("-1", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: '-',
+            position: 0,
+            reason: InvalidReason::UnsignedMinus
+        })
+    );
+}
+
+#[test_case("0x10", Ok(16))]
+#[test_case("-10", Ok(-10))]
+#[test_case("-0o10", Err(StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::DisallowedSign}))]
+fn test_signed_prefixes(s: &str, r: Result<i32, StrToIntError>) {
+    let opts = ParseOptions::new().signed_prefixes(false);
+    assert_eq!(strtoint_with::<i32>(s, &opts), r);
+}
+
+#[test_case(LeadingZeros::Allowed, "0644", Ok(644))]
+#[test_case(LeadingZeros::Allowed, "00", Ok(0))]
+#[test_case(LeadingZeros::AllZeros, "0644", Err(StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero}))]
+#[test_case(LeadingZeros::AllZeros, "00", Ok(0))]
+#[test_case(LeadingZeros::Forbidden, "0644", Err(StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero}))]
+#[test_case(LeadingZeros::Forbidden, "00", Err(StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::LeadingZero}))]
+#[test_case(LeadingZeros::Forbidden, "0", Ok(0))]
+#[test_case(LeadingZeros::Forbidden, "0x00", Ok(0))]
+fn test_leading_zeros(rule: LeadingZeros, s: &str, r: Result<u32, StrToIntError>) {
+    let opts = ParseOptions::new().leading_zeros(rule);
+    assert_eq!(strtoint_with::<u32>(s, &opts), r);
+}
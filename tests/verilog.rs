@@ -0,0 +1,172 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_all_errors_with, strtoint_prefix_with, strtoint_verilog, strtoint_with, ErrorArray,
+    InvalidReason, OutOfRangeKind, Overflow, ParseOptions, StrToIntError,
+};
+use test_case::test_case;
+
+fn verilog() -> ParseOptions {
+    ParseOptions::new().verilog_literals(true)
+}
+
+#[test_case("8'hFF", 255)]
+#[test_case("8'Hff", 255; "upper_base")]
+#[test_case("8'b1010_0101", 0xA5)]
+#[test_case("8'B1111_1111", 255; "upper_b")]
+#[test_case("6'o77", 63)]
+#[test_case("4'd9", 9)]
+#[test_case("4'D15", 15; "upper_d")]
+#[test_case("32'hDEAD_BEEF", 0xDEAD_BEEF)]
+#[test_case("32'shDEAD_BEEF", -559038737)]
+#[test_case("1'b1", 1)]
+#[test_case("1'b0", 0)]
+#[test_case("'hFF", 255)]
+#[test_case("'d1000000", 1000000)]
+#[test_case("-8'd5", -5)]
+#[test_case("+8'd5", 5)]
+#[test_case("8'h0_F_", 15)]
+#[test_case("008'hFF", 255; "width_leading_zeros")]
+#[test_case("42", 42)]
+#[test_case("4'sd3", 3)]
+#[test_case("-4'sd3", -3)]
+#[test_case("4'sb1111", -1)]
+#[test_case("4'SB1000", -8; "upper_s")]
+#[test_case("-4'sb1000", 8)]
+#[test_case("4'sb0111", 7)]
+#[test_case("4'sd8", -8)]
+#[test_case("4'b0_0001", 1)]
+#[test_case("8'shFF", -1)]
+#[test_case("8'sh80", -128)]
+#[test_case("'sd5", 5)]
+#[test_case("'shFFFFFFFF", 0xFFFF_FFFF)]
+#[test_case("64'sh8000_0000_0000_0000", i64::MIN)]
+fn test_verilog(s: &str, x: i64) {
+    assert_eq!(strtoint_with::<i64>(s, &verilog()).unwrap(), x);
+}
+
+#[test]
+fn test_verilog_wide() {
+    assert_eq!(
+        strtoint_with::<i128>("128'shFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF", &verilog()),
+        Ok(-1)
+    );
+    assert_eq!(
+        strtoint_with::<u128>("128'hFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF", &verilog()),
+        Ok(u128::MAX)
+    );
+    assert_eq!(strtoint_with::<u128>("200'sh1", &verilog()), Ok(1));
+    assert_eq!(strtoint_with::<u8>("4294967295'hFF", &verilog()), Ok(255));
+}
+
+#[test_case("4'h1F", StrToIntError::ExceedsWidth {width: 4, position: 4})]
+#[test_case("4'd16", StrToIntError::ExceedsWidth {width: 4, position: 4})]
+#[test_case("1'b10", StrToIntError::ExceedsWidth {width: 1, position: 4})]
+#[test_case("3'o10", StrToIntError::ExceedsWidth {width: 3, position: 4})]
+#[test_case("4'sd8", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: 0, max: 255, position: 4})]
+#[test_case("16'hFFFF", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooLarge, min: 0, max: 255, position: 6})]
+#[test_case("8'shFF", StrToIntError::OutOfRange {kind: OutOfRangeKind::TooSmall, min: 0, max: 255, position: 4})]
+#[test_case("-8'd1", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::UnsignedMinus})]
+#[test_case("4'b1x01", StrToIntError::UnknownDigit {c: 'x', position: 4})]
+#[test_case("4'bZ", StrToIntError::UnknownDigit {c: 'Z', position: 3})]
+#[test_case("8'h?F", StrToIntError::UnknownDigit {c: '?', position: 3})]
+#[test_case("8'dx", StrToIntError::UnknownDigit {c: 'x', position: 3})]
+#[test_case("0'h1", StrToIntError::InvalidCharacter {c: '0', position: 0, reason: InvalidReason::ZeroWidth})]
+#[test_case("99999999999'h1", StrToIntError::InvalidCharacter {c: '9', position: 0, reason: InvalidReason::WidthTooLarge})]
+#[test_case("4294967296'd1", StrToIntError::InvalidCharacter {c: '4', position: 0, reason: InvalidReason::WidthTooLarge})]
+#[test_case("8'h", StrToIntError::NoDigits)]
+#[test_case("8'h_F", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("8'b102", StrToIntError::InvalidCharacter {c: '2', position: 5, reason: InvalidReason::InvalidDigit})]
+#[test_case("8'd-5", StrToIntError::InvalidCharacter {c: '-', position: 3, reason: InvalidReason::SignAfterPrefix})]
+#[test_case("8'q1", StrToIntError::InvalidCharacter {c: '\'', position: 1, reason: InvalidReason::TrailingCharacter})]
+#[test_case("8 'h1", StrToIntError::InvalidCharacter {c: ' ', position: 1, reason: InvalidReason::TrailingCharacter})]
+#[test_case("0xFF", StrToIntError::InvalidCharacter {c: 'x', position: 1, reason: InvalidReason::InvalidDigit})]
+fn test_verilog_u8_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_verilog::<u8>(s).unwrap_err(), err);
+}
+
+#[test]
+fn test_verilog_disabled() {
+    assert_eq!(
+        strtoint_with::<u32>("8'hFF", &ParseOptions::new()),
+        Err(StrToIntError::InvalidCharacter {
+            c: '\'',
+            position: 1,
+            reason: InvalidReason::TrailingCharacter
+        })
+    );
+}
+
+#[test_case("8'hFF", 255, Some(8), false)]
+#[test_case("-4'sd3", -3, Some(4), true)]
+#[test_case("+16'SHFFFF", -1, Some(16), true)]
+#[test_case("'b1010", 10, None, false)]
+#[test_case("'sd7", 7, None, true)]
+#[test_case("42", 42, None, false)]
+#[test_case("-42", -42, None, false)]
+fn test_strtoint_verilog(s: &str, value: i32, width: Option<u32>, signed: bool) {
+    let lit = strtoint_verilog::<i32>(s).unwrap();
+    assert_eq!(lit.value(), value);
+    assert_eq!(lit.width(), width);
+    assert_eq!(lit.is_signed(), signed);
+}
+
+#[test]
+fn test_verilog_saturate() {
+    let opts = verilog().overflow(Overflow::Saturate);
+    assert_eq!(strtoint_with::<u8>("8'shFF", &opts), Ok(0));
+    assert_eq!(strtoint_with::<i8>("16'sh7FFF", &opts), Ok(127));
+    assert_eq!(
+        strtoint_with::<u8>("4'hFF", &opts),
+        Err(StrToIntError::ExceedsWidth {
+            width: 4,
+            position: 4
+        })
+    );
+}
+
+#[test]
+fn test_verilog_wrap() {
+    let opts = verilog().overflow(Overflow::Wrap);
+    assert_eq!(strtoint_with::<u8>("8'shFF", &opts), Ok(255));
+    assert_eq!(strtoint_with::<i8>("16'sh7FFF", &opts), Ok(-1));
+}
+
+#[test_case("8'hFF, 1", 255, ", 1")]
+#[test_case("4'b1010)", 10, ")")]
+#[test_case("8'h1 + 1", 1, " + 1")]
+fn test_verilog_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(
+        strtoint_prefix_with::<u32>(s, &verilog()).unwrap(),
+        (x, rest)
+    );
+}
+
+#[test]
+fn test_verilog_prefix_unknown_digit() {
+    assert_eq!(
+        strtoint_prefix_with::<u32>("4'b10xz", &verilog()),
+        Err(StrToIntError::UnknownDigit {
+            c: 'x',
+            position: 5
+        })
+    );
+}
+
+#[test]
+fn test_verilog_all_errors() {
+    let mut errors = ErrorArray::<4>::new();
+    assert!(strtoint_all_errors_with::<u32>("4'b1x0z1", &verilog(), &mut errors).is_err());
+    assert_eq!(
+        errors.as_slice(),
+        [
+            StrToIntError::UnknownDigit {
+                c: 'x',
+                position: 4
+            },
+            StrToIntError::UnknownDigit {
+                c: 'z',
+                position: 6
+            },
+        ]
+    );
+}
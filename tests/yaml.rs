@@ -0,0 +1,166 @@
+#![cfg(test)]
+use strtoint::{
+    strtoint_all_errors_with, strtoint_prefix_with, strtoint_with, ErrorArray, InvalidReason,
+    OutOfRangeKind, Overflow, ParseOptions, StrToIntError,
+};
+use test_case::test_case;
+
+#[test_case("0", 0)]
+#[test_case("-0", 0)]
+#[test_case("+17", 17)]
+#[test_case("-17", -17)]
+#[test_case("0644", 644)]
+#[test_case("-0644", -644)]
+#[test_case("00", 0)]
+#[test_case("0o17", 15)]
+#[test_case("0o0", 0)]
+#[test_case("0x1F", 31)]
+#[test_case("0x1f", 31; "lowercase_hex")]
+fn test_yaml_core(s: &str, x: i32) {
+    assert_eq!(strtoint_with::<i32>(s, &ParseOptions::yaml_core()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("0x", StrToIntError::NoDigits)]
+#[test_case("-0x1F", StrToIntError::InvalidCharacter {c: '-', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("+0o17", StrToIntError::InvalidCharacter {c: '+', position: 0, reason: InvalidReason::DisallowedSign})]
+#[test_case("0X1F", StrToIntError::InvalidCharacter {c: 'X', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0O17", StrToIntError::InvalidCharacter {c: 'O', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0b1", StrToIntError::InvalidCharacter {c: 'b', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("1_000", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("0x_1", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1:30", StrToIntError::InvalidCharacter {c: ':', position: 1, reason: InvalidReason::TrailingCharacter})]
+fn test_yaml_core_err(s: &str, err: StrToIntError) {
+    assert_eq!(
+        strtoint_with::<i32>(s, &ParseOptions::yaml_core()),
+        Err(err)
+    );
+}
+
+// Expected values were checked against PyYAML's `safe_load()`.
+#[test_case("0", 0)]
+#[test_case("-0", 0)]
+#[test_case("+0", 0)]
+#[test_case("00", 0)]
+#[test_case("0_", 0)]
+#[test_case("0__7", 7)]
+#[test_case("0644", 420)]
+#[test_case("-0644", -420)]
+#[test_case("1__2", 12)]
+#[test_case("1_", 1)]
+#[test_case("0b1010_0111", 167)]
+#[test_case("+0b1", 1)]
+#[test_case("-0b_1", -1)]
+#[test_case("0x_1F", 31)]
+#[test_case("-0x1F_", -31)]
+#[test_case("190:20:30", 685_230)]
+#[test_case("-1:05", -65)]
+#[test_case("+1:5", 65)]
+#[test_case("1:0", 60)]
+#[test_case("1_0:30", 630)]
+#[test_case("1_:30", 90)]
+#[test_case("1:05:59", 3959)]
+#[test_case("1:59:59:59", 431_999)]
+fn test_yaml_1_1(s: &str, x: i64) {
+    assert_eq!(strtoint_with::<i64>(s, &ParseOptions::yaml_1_1()), Ok(x));
+}
+
+#[test_case("", StrToIntError::NoDigits)]
+#[test_case("0b", StrToIntError::NoDigits)]
+#[test_case("0b_", StrToIntError::NoDigits; "binary_underscore")]
+#[test_case("0x", StrToIntError::NoDigits)]
+#[test_case("09", StrToIntError::InvalidCharacter {c: '9', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0X1F", StrToIntError::InvalidCharacter {c: 'X', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("0o17", StrToIntError::InvalidCharacter {c: 'o', position: 1, reason: InvalidReason::InvalidDigit})]
+#[test_case("_1", StrToIntError::InvalidCharacter {c: '_', position: 0, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("+_1", StrToIntError::InvalidCharacter {c: '_', position: 1, reason: InvalidReason::LeadingUnderscore})]
+#[test_case("1:60", StrToIntError::InvalidCharacter {c: '6', position: 2, reason: InvalidReason::InvalidSexagesimal})]
+#[test_case("1:123", StrToIntError::InvalidCharacter {c: '1', position: 2, reason: InvalidReason::InvalidSexagesimal})]
+#[test_case("1:", StrToIntError::InvalidCharacter {c: ':', position: 1, reason: InvalidReason::InvalidSexagesimal})]
+#[test_case("1::30", StrToIntError::InvalidCharacter {c: ':', position: 2, reason: InvalidReason::InvalidSexagesimal})]
+#[test_case("1:_30", StrToIntError::InvalidCharacter {c: '_', position: 2, reason: InvalidReason::InvalidSexagesimal})]
+#[test_case("1:3_0", StrToIntError::InvalidCharacter {c: '_', position: 3, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1:30_", StrToIntError::InvalidCharacter {c: '_', position: 4, reason: InvalidReason::MisplacedUnderscore})]
+#[test_case("1:5a", StrToIntError::InvalidCharacter {c: 'a', position: 3, reason: InvalidReason::InvalidDigit})]
+#[test_case("0:30", StrToIntError::InvalidCharacter {c: ':', position: 1, reason: InvalidReason::TrailingCharacter})]
+#[test_case("0x1:30", StrToIntError::InvalidCharacter {c: ':', position: 3, reason: InvalidReason::TrailingCharacter})]
+#[test_case(":30", StrToIntError::InvalidCharacter {c: ':', position: 0, reason: InvalidReason::UnexpectedCharacter})]
+fn test_yaml_1_1_err(s: &str, err: StrToIntError) {
+    assert_eq!(strtoint_with::<i64>(s, &ParseOptions::yaml_1_1()), Err(err));
+}
+
+#[test]
+fn test_sexagesimal_out_of_range() {
+    let opts = ParseOptions::yaml_1_1();
+    // 4:15 is 255, and 4:16 is 256.
+    assert_eq!(strtoint_with::<u8>("4:15", &opts), Ok(255));
+    assert_eq!(
+        strtoint_with::<u8>("4:16", &opts),
+        Err(StrToIntError::OutOfRange {
+            kind: OutOfRangeKind::TooLarge,
+            min: 0,
+            max: 255,
+            position: 3
+        })
+    );
+    assert_eq!(
+        strtoint_with::<u8>("4:16", &opts.overflow(Overflow::Wrap)),
+        Ok(0)
+    );
+    assert_eq!(
+        strtoint_with::<u8>("5:00", &opts.overflow(Overflow::Wrap)),
+        Ok(44)
+    );
+    assert_eq!(
+        strtoint_with::<u8>("9:59", &opts.overflow(Overflow::Saturate)),
+        Ok(255)
+    );
+}
+
+#[test_case("1:30 rest", 90, " rest")]
+#[test_case("1:60", 1, ":60")]
+#[test_case("1:", 1, ":")]
+#[test_case("1:30_", 90, "_")]
+fn test_sexagesimal_prefix(s: &str, x: u32, rest: &str) {
+    assert_eq!(
+        strtoint_prefix_with::<u32>(s, &ParseOptions::yaml_1_1()),
+        Ok((x, rest))
+    );
+}
+
+#[test]
+fn test_sexagesimal_all_errors() {
+    let mut errors = ErrorArray::<4>::new();
+    assert!(
+        strtoint_all_errors_with::<u32>("1:60:7_5", &ParseOptions::yaml_1_1(), &mut errors)
+            .is_err()
+    );
+    assert_eq!(
+        errors.as_slice(),
+        [
+            StrToIntError::InvalidCharacter {
+                c: '6',
+                position: 2,
+                reason: InvalidReason::InvalidSexagesimal
+            },
+            StrToIntError::InvalidCharacter {
+                c: '_',
+                position: 6,
+                reason: InvalidReason::MisplacedUnderscore
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_sexagesimal_needs_decimal() {
+    let opts = ParseOptions::new().sexagesimal(true).default_radix(16);
+    assert_eq!(
+        strtoint_with::<u32>("1:30", &opts),
+        Err(StrToIntError::InvalidCharacter {
+            c: ':',
+            position: 1,
+            reason: InvalidReason::TrailingCharacter
+        })
+    );
+}